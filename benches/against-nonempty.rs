@@ -4,7 +4,7 @@ use divan::Bencher;
 
 const LENS: &[usize] = &[1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024];
 
-#[divan::bench(types = [Ours<String>, Theirs<String>, Ours<u8>, Theirs<u8>], args = LENS)]
+#[divan::bench(types = [Ours<String>, Head<String>, Theirs<String>, Ours<u8>, Head<u8>, Theirs<u8>], args = LENS)]
 fn clone<B: BenchMe>(bencher: Bencher, len: usize)
 where
     B::Subject: Clone,
@@ -13,7 +13,7 @@ where
     bencher.bench_local(move || B::run_clone(&bench_me));
 }
 
-#[divan::bench(types = [Ours<String>, Theirs<String>, Ours<u8>, Theirs<u8>], args = LENS)]
+#[divan::bench(types = [Ours<String>, Head<String>, Theirs<String>, Ours<u8>, Head<u8>, Theirs<u8>], args = LENS)]
 fn into_iter<B: BenchMe>(bencher: Bencher, len: usize)
 where
     B::Subject: Clone + IntoIterator,
@@ -21,7 +21,7 @@ where
     let bench_me = black_box(B::setup(len));
     bencher.bench_local(move || B::run_into_iter(&bench_me));
 }
-#[divan::bench(types = [Ours<String>, Theirs<String>, Ours<u8>, Theirs<u8>], args = LENS)]
+#[divan::bench(types = [Ours<String>, Head<String>, Theirs<String>, Ours<u8>, Head<u8>, Theirs<u8>], args = LENS)]
 fn iter<B: BenchMe>(bencher: Bencher, len: usize)
 where
     for<'a> &'a B::Subject: IntoIterator,
@@ -29,7 +29,7 @@ where
     let bench_me = black_box(B::setup(len));
     bencher.bench_local(move || B::run_iter(&bench_me));
 }
-#[divan::bench(types = [Ours<String>, Theirs<String>, Ours<u8>, Theirs<u8>], args = LENS)]
+#[divan::bench(types = [Ours<String>, Head<String>, Theirs<String>, Ours<u8>, Head<u8>, Theirs<u8>], args = LENS)]
 fn partial_eq<B: BenchMe>(bencher: Bencher, len: usize)
 where
     B::Subject: PartialEq,
@@ -40,6 +40,7 @@ where
 }
 
 struct Ours<T>(std::marker::PhantomData<fn() -> T>);
+struct Head<T>(std::marker::PhantomData<fn() -> T>);
 struct Theirs<T>(std::marker::PhantomData<fn() -> T>);
 trait BenchMe {
     type Subject;
@@ -89,6 +90,21 @@ where
         })
     }
 }
+impl<T> BenchMe for Head<T>
+where
+    T: Default,
+{
+    type Subject = nunny::HeadVec<T>;
+
+    fn setup(len: usize) -> Self::Subject {
+        let mut src = Vec::new();
+        src.resize_with(len, Default::default);
+        black_box(match nunny::HeadVec::new(src) {
+            Ok(it) => it,
+            Err(_) => panic!(),
+        })
+    }
+}
 impl<T> BenchMe for Theirs<T>
 where
     T: Default,
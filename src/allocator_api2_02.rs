@@ -0,0 +1,773 @@
+//! Support for non-global allocators, using [`allocator_api2`](allocator_api2_02)
+//! on stable Rust.
+//!
+//! [`NonEmpty<allocator_api2::vec::Vec<T, A>>`](NonEmpty) mirrors the API of
+//! [`Vec`](crate::Vec).
+//!
+//! Note that coherence rules forbid us from implementing foreign traits like
+//! [`Clone`] and [`IntoIterator`] for `allocator_api2::boxed::Box<Slice<T>, A>`,
+//! so those are available by converting to a [`NonEmpty`] vec.
+//!
+//! With `allocator-api2`'s `nightly` feature, its `Vec` is a re-export of
+//! [`alloc::vec::Vec`], so every impl here would overlap with [`crate::Vec`].
+//! This module is compiled out under `--cfg nunny_allocator_api2_nightly`.
+
+use core::{
+    cmp::Ordering,
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use allocator_api2_02::{
+    alloc::{Allocator, Global},
+    boxed::Box,
+    collections::TryReserveError,
+    vec::{IntoIter, Vec},
+};
+
+use crate::{Error, NonEmpty, Slice};
+
+impl<T, A> Eq for NonEmpty<Vec<T, A>>
+where
+    T: Eq,
+    A: Allocator,
+{
+}
+
+/// [`allocator_api2::vec::Vec`](allocator_api2_02::vec::Vec) methods
+impl<T, A> NonEmpty<Vec<T, A>>
+where
+    A: Allocator,
+{
+    ///////////
+    // Creation
+    ///////////
+
+    /// Create a new [`NonEmpty`] heap-allocated vec, returning the original
+    /// allocation if it was empty.
+    pub fn new(src: Vec<T, A>) -> Result<Self, Vec<T, A>> {
+        match src.is_empty() {
+            false => Ok(unsafe { Self::new_unchecked(src) }),
+            true => Err(src),
+        }
+    }
+    crate::map_non_empty! {
+        /// Create a new [`NonEmpty`] heap-allocated vec
+        new_ref(&Vec<T, A>) -> &Self: Self::new_ref_unchecked;
+        /// Create a new [`NonEmpty`] heap-allocated vec
+        new_mut(&mut Vec<T, A>) -> &mut Self: Self::new_mut_unchecked;
+    }
+    /// Create a new [`NonEmpty`] heap-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_unchecked(src: Vec<T, A>) -> Self {
+        debug_assert!(!src.is_empty());
+        Self { inner: src }
+    }
+    /// Create a new [`NonEmpty`] heap-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_ref_unchecked(src: &Vec<T, A>) -> &Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &*(src as *const Vec<T, A> as *const Self) }
+    }
+    /// Create a new [`NonEmpty`] heap-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_mut_unchecked(src: &mut Vec<T, A>) -> &mut Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &mut *(src as *mut Vec<T, A> as *mut Self) }
+    }
+
+    ////////////
+    // Utilities
+    ////////////
+
+    /// Create a [`NonEmpty`] heap-allocated vec in the given allocator, of a
+    /// single element.
+    pub fn of_in(item: T, alloc: A) -> Self {
+        Self::of_with_capacity_in(item, 1, alloc)
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec in the given allocator, of a
+    /// single element, with capacity for `capacity` elements without
+    /// (re)-allocating.
+    pub fn of_with_capacity_in(item: T, capacity: usize, alloc: A) -> Self {
+        let mut inner = Vec::with_capacity_in(capacity, alloc);
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Creating a [`NonEmpty`] heap-allocated vec in the given allocator,
+    /// where the first element is known.
+    pub fn of_extending_in<I>(first: T, rest: I, alloc: A) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let rest = rest.into_iter();
+        let mut this = Self::of_with_capacity_in(first, rest.size_hint().0, alloc);
+        this.extend(rest);
+        this
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec in the given allocator, with
+    /// `len` items, filled with [`Clone`]s of the given `value`.
+    pub fn filled_in(value: T, len: NonZeroUsize, alloc: A) -> Self
+    where
+        T: Clone,
+    {
+        let mut inner = Vec::new_in(alloc);
+        inner.resize(len.get(), value);
+        // Safety:
+        // - len is nonzero
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec in the given allocator, with
+    /// `len` items, filled with values returned from repeating the closure `f`.
+    pub fn filled_with_in<F>(f: F, len: NonZeroUsize, alloc: A) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        let mut inner = Vec::new_in(alloc);
+        inner.resize_with(len.get(), f);
+        // Safety:
+        // - len is nonzero
+        unsafe { Self::new_unchecked(inner) }
+    }
+    fn check(&self) {
+        debug_assert_ne!(self.inner.len(), 0)
+    }
+
+    /// Returns an [`allocator_api2::vec::Vec`](allocator_api2_02::vec::Vec).
+    pub fn as_vec(&self) -> &Vec<T, A> {
+        self.check();
+        &self.inner
+    }
+    /// Returns an [`allocator_api2::vec::Vec`](allocator_api2_02::vec::Vec).
+    ///
+    /// # Safety
+    /// - returned vec must not be emptied through this reference
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<T, A> {
+        self.check();
+        &mut self.inner
+    }
+    /// Returns an [`allocator_api2::vec::Vec`](allocator_api2_02::vec::Vec).
+    pub fn into_vec(self) -> Vec<T, A> {
+        let Self { inner } = self;
+        inner
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_slice_ne(&self) -> &Slice<T> {
+        unsafe { Slice::new_unchecked(self.as_vec()) }
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_mut_slice_ne(&mut self) -> &mut Slice<T> {
+        unsafe { Slice::new_mut_unchecked(self.as_mut_vec()) }
+    }
+    /// Returns a reference to the underlying allocator.
+    pub fn allocator(&self) -> &A {
+        self.as_vec().allocator()
+    }
+
+    //////////////////
+    // Shimmed methods (rustdoc order)
+    //////////////////
+
+    /// Returns the known non-zero length.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+
+    crate::forward_mut! {
+        "allocator_api2_02::vec::Vec";
+        pub fn reserve(&mut self, additional: usize);
+        pub fn reserve_exact(&mut self, additional: usize);
+        pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
+        pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError>;
+        pub fn shrink_to_fit(&mut self);
+        pub fn shrink_to(&mut self, min_capacity: usize);
+    }
+
+    /// Return a [`NonEmpty`] boxed slice, in the same allocator.
+    pub fn into_boxed_slice(self) -> Box<Slice<T>, A> {
+        let (ptr, alloc) = Box::into_raw_with_allocator(self.into_vec().into_boxed_slice());
+        // Safety:
+        // - #[repr(transparent)]
+        // - originating slice is non-empty by construction
+        unsafe { Box::from_raw_in(ptr as *mut Slice<T>, alloc) }
+    }
+
+    /// Create a [`NonEmpty`] vec from a [`NonEmpty`] boxed slice, without
+    /// reallocating.
+    pub fn from_boxed_slice(src: Box<Slice<T>, A>) -> Self {
+        let (ptr, alloc) = Box::into_raw_with_allocator(src);
+        // Safety:
+        // - #[repr(transparent)]
+        let src = unsafe { Box::from_raw_in(ptr as *mut [T], alloc) };
+        // Safety:
+        // - originating slice is non-empty by construction
+        unsafe { Self::new_unchecked(src.into_vec()) }
+    }
+
+    /// Shortens the vector to a guaranteed-nonzero length
+    ///
+    /// See [`truncate`](allocator_api2_02::vec::Vec::truncate).
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        // Safety:
+        // - len is not zero, so vector will not be emptied
+        unsafe { self.as_mut_vec() }.truncate(len.get());
+        self.check();
+    }
+
+    /// # Safety
+    /// - See [`set_len`](allocator_api2_02::vec::Vec::set_len).
+    pub unsafe fn set_len(&mut self, new_len: NonZeroUsize) {
+        // Safety:
+        // - len is not zero, so vector will not be emptied
+        unsafe { self.as_mut_vec() }.set_len(new_len.get());
+        self.check();
+    }
+
+    crate::forward_mut! {
+        "allocator_api2_02::vec::Vec";
+        pub fn insert(&mut self, index: usize, element: T);
+    }
+
+    /// See [`dedup_by_key`](allocator_api2_02::vec::Vec::dedup_by_key).
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by_key(key);
+        self.check();
+    }
+    /// See [`dedup_by`](allocator_api2_02::vec::Vec::dedup_by).
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by(same_bucket);
+        self.check();
+    }
+    crate::forward_mut! {
+        "allocator_api2_02::vec::Vec";
+        pub fn push(&mut self, value: T);
+        pub fn append(&mut self, other: &mut Vec<T, A>);
+    }
+
+    /// See [`resize_with`](allocator_api2_02::vec::Vec::resize_with).
+    pub fn resize_with<F>(&mut self, new_len: NonZeroUsize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        // Safety:
+        // - new_len is not zero, so vec cannot be emptied
+        unsafe { self.as_mut_vec() }.resize_with(new_len.get(), f);
+        self.check();
+    }
+    /// Returns a [`NonEmpty`] slice.
+    ///
+    /// See [`leak`](allocator_api2_02::vec::Vec::leak).
+    pub fn leak<'a>(self) -> &'a mut Slice<T>
+    where
+        A: 'a,
+    {
+        let inner = self.into_vec().leak();
+        // Safety:
+        // - originating slice is non-empty by construction
+        unsafe { Slice::new_mut_unchecked(inner) }
+    }
+    crate::forward_mut! {
+        "allocator_api2_02::vec::Vec";
+        pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>];
+    }
+}
+
+/// Global allocator constructors.
+impl<T> NonEmpty<Vec<T, Global>> {
+    /// Create a [`NonEmpty`] heap-allocated vec, of a single element.
+    pub fn of(item: T) -> Self {
+        Self::of_in(item, Global)
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec, of a single element, with
+    /// capacity for `capacity` elements without (re)-allocating.
+    pub fn of_with_capacity(item: T, capacity: usize) -> Self {
+        Self::of_with_capacity_in(item, capacity, Global)
+    }
+    /// Creating a [`NonEmpty`] heap-allocated vec where the first element is known.
+    pub fn of_extending<I>(first: T, rest: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::of_extending_in(first, rest, Global)
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec with `len` items, filled with
+    /// [`Clone`]s of the given `value`.
+    pub fn filled(value: T, len: NonZeroUsize) -> Self
+    where
+        T: Clone,
+    {
+        Self::filled_in(value, len, Global)
+    }
+    /// Create a [`NonEmpty`] heap-allocated vec with `len` items, filled with
+    /// values returned from repeating the closure `f`.
+    pub fn filled_with<F>(f: F, len: NonZeroUsize) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::filled_with_in(f, len, Global)
+    }
+}
+
+/// Known non-empty iterator for [`NonEmpty<allocator_api2::vec::Vec>`](NonEmpty).
+impl<T, A> NonEmpty<Vec<T, A>>
+where
+    A: Allocator,
+{
+    pub fn into_iter_ne(self) -> NonEmpty<IntoIter<T, A>> {
+        NonEmpty {
+            inner: self.into_vec().into_iter(),
+        }
+    }
+}
+
+impl<T, A> Deref for NonEmpty<Vec<T, A>>
+where
+    A: Allocator,
+{
+    type Target = Slice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_ne()
+    }
+}
+
+impl<T, A> DerefMut for NonEmpty<Vec<T, A>>
+where
+    A: Allocator,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_ne()
+    }
+}
+
+crate::as_ref_as_mut! {
+    <T, A: Allocator> for NonEmpty<Vec<T, A>> as [T];
+    <T, A: Allocator> for NonEmpty<Vec<T, A>> as Slice<T>;
+}
+
+crate::borrow_borrow_mut! {
+    <T, A: Allocator> for NonEmpty<Vec<T, A>> as [T];
+    <T, A: Allocator> for NonEmpty<Vec<T, A>> as Slice<T>;
+}
+
+mod iter {
+    use super::*;
+
+    impl<T, A> IntoIterator for NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Item = T;
+
+        type IntoIter = IntoIter<T, A>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.into_vec().into_iter()
+        }
+    }
+    impl<'a, T, A> IntoIterator for &'a NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Item = &'a T;
+        type IntoIter = core::slice::Iter<'a, T>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+    impl<'a, T, A> IntoIterator for &'a mut NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Item = &'a mut T;
+        type IntoIter = core::slice::IterMut<'a, T>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+    impl<'a, T, A> Extend<&'a T> for NonEmpty<Vec<T, A>>
+    where
+        T: Copy + 'a,
+        A: Allocator + 'a,
+    {
+        fn extend<II: IntoIterator<Item = &'a T>>(&mut self, iter: II) {
+            // Safety:
+            // - append-only operation
+            unsafe { self.as_mut_vec() }.extend(iter)
+        }
+    }
+    impl<T, A> Extend<T> for NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        fn extend<II: IntoIterator<Item = T>>(&mut self, iter: II) {
+            // Safety:
+            // - append-only operation
+            unsafe { self.as_mut_vec() }.extend(iter)
+        }
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<T, U, A, B> PartialEq<NonEmpty<Vec<U, B>>> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialEq<U>,
+        A: Allocator,
+        B: Allocator,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<U, B>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A> PartialEq<[U]> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialEq<U>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A, const N: usize> PartialEq<[U; N]> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialEq<U>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A> PartialEq<Slice<U>> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialEq<U>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &Slice<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A> PartialEq<Vec<U, A>> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialEq<U>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &Vec<U, A>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, U, A> PartialEq<NonEmpty<Vec<T, A>>> for [U]
+    where
+        U: PartialEq<T>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A, const N: usize> PartialEq<NonEmpty<Vec<T, A>>> for [U; N]
+    where
+        U: PartialEq<T>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A> PartialEq<NonEmpty<Vec<T, A>>> for Slice<U>
+    where
+        U: PartialEq<T>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, A> PartialEq<NonEmpty<Vec<T, A>>> for Vec<U, A>
+    where
+        U: PartialEq<T>,
+        A: Allocator,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod cmp {
+    use super::*;
+
+    impl<T, A> PartialOrd for NonEmpty<Vec<T, A>>
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            <[_] as PartialOrd>::partial_cmp(self, other)
+        }
+    }
+    impl<T, A> Ord for NonEmpty<Vec<T, A>>
+    where
+        T: Ord,
+        A: Allocator,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            <[_] as Ord>::cmp(self, other)
+        }
+    }
+    impl<T, A> PartialOrd<[T]> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, A, const N: usize> PartialOrd<[T; N]> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, A> PartialOrd<Vec<T, A>> for NonEmpty<Vec<T, A>>
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &Vec<T, A>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, A> PartialOrd<NonEmpty<Vec<T, A>>> for [T]
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &NonEmpty<Vec<T, A>>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, A, const N: usize> PartialOrd<NonEmpty<Vec<T, A>>> for [T; N]
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &NonEmpty<Vec<T, A>>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, A> PartialOrd<NonEmpty<Vec<T, A>>> for Vec<T, A>
+    where
+        T: PartialOrd,
+        A: Allocator,
+    {
+        fn partial_cmp(&self, other: &NonEmpty<Vec<T, A>>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<T, A> TryFrom<Vec<T, A>> for NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Error = Vec<T, A>;
+
+        fn try_from(value: Vec<T, A>) -> Result<Self, Self::Error> {
+            Self::new(value)
+        }
+    }
+    impl<'a, T, A> TryFrom<&'a Vec<T, A>> for &'a NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Error = Error;
+
+        fn try_from(value: &'a Vec<T, A>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<T, A>>::new_ref(value).ok_or(Error(()))
+        }
+    }
+    impl<'a, T, A> TryFrom<&'a mut Vec<T, A>> for &'a mut NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        type Error = Error;
+
+        fn try_from(value: &'a mut Vec<T, A>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<T, A>>::new_mut(value).ok_or(Error(()))
+        }
+    }
+
+    impl<T, A> From<NonEmpty<Vec<T, A>>> for Vec<T, A>
+    where
+        A: Allocator,
+    {
+        fn from(value: NonEmpty<Vec<T, A>>) -> Self {
+            value.into_vec()
+        }
+    }
+    impl<T, A> From<NonEmpty<Vec<T, A>>> for Box<Slice<T>, A>
+    where
+        A: Allocator,
+    {
+        fn from(value: NonEmpty<Vec<T, A>>) -> Self {
+            value.into_boxed_slice()
+        }
+    }
+    impl<T, A> From<Box<Slice<T>, A>> for NonEmpty<Vec<T, A>>
+    where
+        A: Allocator,
+    {
+        fn from(value: Box<Slice<T>, A>) -> Self {
+            Self::from_boxed_slice(value)
+        }
+    }
+    impl<T, A> From<NonEmpty<Vec<T, A>>> for Box<[T], A>
+    where
+        A: Allocator,
+    {
+        fn from(value: NonEmpty<Vec<T, A>>) -> Self {
+            value.into_vec().into_boxed_slice()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+    use allocator_api2_02::alloc::AllocError;
+
+    use super::*;
+
+    /// Counts live allocations.
+    #[derive(Default)]
+    struct Counting(Cell<usize>);
+
+    unsafe impl Allocator for Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let ptr = Global.allocate(layout)?;
+            self.0.set(self.0.get() + 1);
+            Ok(ptr)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.set(self.0.get() - 1);
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn custom_allocator() {
+        let alloc = Counting::default();
+        let mut v = NonEmpty::<Vec<_, _>>::filled_in(0, NonZeroUsize::MIN, &alloc);
+        assert_eq!(alloc.0.get(), 1);
+        v.push(1);
+        assert_eq!(v, [0, 1]);
+        assert_eq!(alloc.0.get(), 1);
+
+        let boxed = v.into_boxed_slice();
+        assert_eq!(*boxed, [0, 1]);
+        let v = NonEmpty::<Vec<_, _>>::from(boxed);
+        assert_eq!(v.allocator().0.get(), 1);
+        drop(v);
+        assert_eq!(alloc.0.get(), 0);
+    }
+
+    #[test]
+    fn never_emptied() {
+        let mut v = NonEmpty::<Vec<_>>::of_extending(1, [1, 2, 2, 3]);
+        v.dedup_by_key(|it| *it);
+        assert_eq!(v, [1, 2, 3]);
+        v.truncate(NonZeroUsize::MIN);
+        assert_eq!(v, [1]);
+        v.resize_with(NonZeroUsize::new(3).unwrap(), || 0);
+        assert_eq!(v, [1, 0, 0]);
+        let mut zeros = 0;
+        let mut filled = NonEmpty::<Vec<_>>::filled(1, NonZeroUsize::MIN);
+        filled.extend(NonEmpty::<Vec<_>>::filled_with(
+            || {
+                zeros += 1;
+                0
+            },
+            NonZeroUsize::new(2).unwrap(),
+        ));
+        assert_eq!(zeros, 2);
+        assert_eq!(v, filled);
+        assert!(v.try_reserve(usize::MAX).is_err());
+        assert_eq!(v, [1, 0, 0]);
+    }
+
+    #[test]
+    fn convert() {
+        let mut empty = Vec::<i32>::new();
+        assert!(<&NonEmpty<Vec<_>>>::try_from(&empty).is_err());
+        assert!(<&mut NonEmpty<Vec<_>>>::try_from(&mut empty).is_err());
+        let empty = NonEmpty::<Vec<_>>::new(empty).unwrap_err();
+
+        let mut raw = empty;
+        raw.push(1);
+        let v = NonEmpty::<Vec<_>>::try_from(raw).unwrap();
+        assert_eq!(v, *Slice::new(&[1]).unwrap());
+        assert!(v < [2]);
+        assert!([0] < v);
+        let raw = Vec::from(v);
+        assert_eq!(raw, [1]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut v = NonEmpty::<Vec<_>>::of_in(1, Global);
+        v.extend([2, 3]);
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(*v.last(), 3);
+
+        let boxed: Box<Slice<i32>> = v.clone().into_boxed_slice();
+        assert_eq!(*boxed, [1, 2, 3]);
+        let v2 = NonEmpty::<Vec<_>>::from_boxed_slice(boxed);
+        assert_eq!(v, v2);
+        assert_eq!(v2.into_iter_ne().rev().first(), 3);
+
+        let leaked: &mut Slice<i32> = v.leak();
+        assert_eq!(*leaked.first(), 1);
+    }
+}
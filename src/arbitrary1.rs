@@ -1,24 +1,63 @@
-use arbitrary1::Arbitrary;
+//! Structured fuzzing with [`arbitrary`](arbitrary1).
+//!
+//! Collections always contain at least one element, and size hints are derived
+//! from those of the element type.
 
-use crate::{Slice, Vec};
+use arbitrary1::{Arbitrary, Error, MaxRecursionReached, Result, Unstructured};
+
+use crate::{Array, ArrayVec, HeadVec, Slice, Vec};
+
+/// At least one `T`, with no upper bound.
+fn at_least_one<'a, T: Arbitrary<'a>>(
+    depth: usize,
+) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+    Ok((T::try_size_hint(depth)?.0, None))
+}
 
 impl<'a> Arbitrary<'a> for &'a Slice<u8> {
-    fn arbitrary(u: &mut arbitrary1::Unstructured<'a>) -> arbitrary1::Result<Self> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
         let len = u.arbitrary_len::<u8>()?.saturating_add(1);
         Ok(Slice::new(u.bytes(len)?).expect("`len` was non-zero"))
     }
 
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Slice::new(u.take_rest()).ok_or(Error::NotEnoughData)
+    }
+
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
         let _ = depth;
         (1, None)
     }
 }
 
+impl<'a, T, const N: usize> Arbitrary<'a> for Array<T, N>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Array::new_nonzero(u.arbitrary()?))
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(Array::new_nonzero(Arbitrary::arbitrary_take_rest(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[T; N]>::size_hint(depth)
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        <[T; N]>::try_size_hint(depth)
+    }
+}
+
 impl<'a, T> Arbitrary<'a> for Vec<T>
 where
     T: Arbitrary<'a>,
 {
-    fn arbitrary(u: &mut arbitrary1::Unstructured<'a>) -> arbitrary1::Result<Self> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
         let mut it = Vec::of(u.arbitrary::<T>()?);
         for item in u.arbitrary_iter()? {
             it.push(item?)
@@ -26,9 +65,22 @@ where
         Ok(it)
     }
 
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let mut it = Vec::of(u.arbitrary::<T>()?);
+        for item in u.arbitrary_take_rest_iter()? {
+            it.push(item?)
+        }
+        Ok(it)
+    }
+
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        let _ = depth;
-        (1, None)
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        at_least_one::<T>(depth)
     }
 }
 
@@ -36,12 +88,120 @@ impl<'a, T> Arbitrary<'a> for Box<Slice<T>>
 where
     T: Arbitrary<'a>,
 {
-    fn arbitrary(u: &mut arbitrary1::Unstructured<'a>) -> arbitrary1::Result<Self> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
         Ok(Vec::arbitrary(u)?.into_boxed_slice())
     }
 
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(Vec::arbitrary_take_rest(u)?.into_boxed_slice())
+    }
+
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        let _ = depth;
-        (1, None)
+        Vec::<T>::size_hint(depth)
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        Vec::<T>::try_size_hint(depth)
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for HeadVec<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut it = HeadVec::of(u.arbitrary::<T>()?);
+        for item in u.arbitrary_iter()? {
+            it.push(item?)
+        }
+        Ok(it)
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let mut it = HeadVec::of(u.arbitrary::<T>()?);
+        for item in u.arbitrary_take_rest_iter()? {
+            it.push(item?)
+        }
+        Ok(it)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        at_least_one::<T>(depth)
+    }
+}
+
+impl<'a, T, const CAP: usize> Arbitrary<'a> for ArrayVec<T, CAP>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut it = ArrayVec::of(u.arbitrary::<T>()?);
+        for item in u.arbitrary_iter()? {
+            if it.is_full() {
+                break;
+            }
+            let _ = it.try_push(item?);
+        }
+        Ok(it)
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let mut it = ArrayVec::of(u.arbitrary::<T>()?);
+        for item in u.arbitrary_take_rest_iter()? {
+            if it.is_full() {
+                break;
+            }
+            let _ = it.try_push(item?);
+        }
+        Ok(it)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        at_least_one::<T>(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint() {
+        assert_eq!(Vec::<u32>::size_hint(0), (4, None));
+        assert_eq!(Box::<Slice<u16>>::size_hint(0), (2, None));
+        assert_eq!(Array::<u32, 2>::size_hint(0), (8, Some(8)));
+        assert_eq!(Vec::<Array<u8, 3>>::size_hint(0), (3, None));
+    }
+
+    #[test]
+    fn take_rest() {
+        let data = [1, 2, 3, 4, 5];
+        let slice = <&Slice<u8>>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(slice, &data);
+        assert!(<&Slice<u8>>::arbitrary_take_rest(Unstructured::new(&[])).is_err());
+
+        let v = Vec::<u8>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(v[0], 1);
+        let array = Array::<u8, 2>::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(array[0], 1);
+
+        let v = ArrayVec::<u8, 2>::arbitrary(&mut Unstructured::new(&[0; 64])).unwrap();
+        assert!(v.len() <= 2);
+        let v = HeadVec::<u8>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!(v, [0]);
     }
 }
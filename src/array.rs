@@ -44,6 +44,25 @@ impl<const N: usize, T> Array<T, N> {
         Self { inner: src }
     }
 
+    /// Create a [`NonEmpty`] array, failing to compile if `N` is zero.
+    #[cfg_attr(
+        not(any(
+            feature = "arbitrary1",
+            feature = "bolero013",
+            feature = "proptest1",
+            feature = "quickcheck1",
+            feature = "rand09"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn new_nonzero(src: [T; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = crate::array_vec::AssertCap::<N, 1>::NONZERO;
+        // Safety:
+        // - `N` is not zero
+        unsafe { Self::new_unchecked(src) }
+    }
+
     ////////////
     // Utilities
     ////////////
@@ -0,0 +1,646 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut, Range},
+    ptr, slice,
+};
+
+use crate::{Array, NonEmpty, Slice};
+
+/// A non-empty, fixed-capacity vector which stores its elements inline.
+///
+/// This does not allocate, so is available without the `alloc` feature.
+/// `CAP` must not be zero.
+/// ```
+/// # use nunny::ArrayVec;
+/// let mut v = ArrayVec::<_, 2>::of("hello");
+/// assert_eq!(v.try_push("world"), Ok(()));
+/// assert_eq!(v.try_push("!"), Err("!")); // at capacity
+/// assert_eq!(v, ["hello", "world"]);
+/// ```
+/// ```compile_fail
+/// # use nunny::ArrayVec;
+/// let v = ArrayVec::<_, 0>::of("oops"); // no capacity!
+/// ```
+pub struct ArrayVec<T, const CAP: usize> {
+    /// Never zero.
+    len: usize,
+    buf: [MaybeUninit<T>; CAP],
+}
+
+/// Compile-time checks on a capacity (or array length) `CAP`.
+pub(crate) struct AssertCap<const CAP: usize, const N: usize>;
+impl<const CAP: usize, const N: usize> AssertCap<CAP, N> {
+    pub(crate) const NONZERO: () = assert!(CAP != 0, "capacity or length must not be zero");
+    pub(crate) const FITS: () = assert!(N <= CAP, "array is larger than capacity");
+}
+
+fn uninit_array<T, const CAP: usize>() -> [MaybeUninit<T>; CAP] {
+    // Safety:
+    // - an array of `MaybeUninit` does not require initialization
+    unsafe { MaybeUninit::<[MaybeUninit<T>; CAP]>::uninit().assume_init() }
+}
+
+/// [`ArrayVec`] methods
+impl<T, const CAP: usize> ArrayVec<T, CAP> {
+    ///////////
+    // Creation
+    ///////////
+
+    /// Create an [`ArrayVec`] of a single element.
+    pub fn of(item: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCap::<CAP, 1>::NONZERO;
+        let mut buf = uninit_array::<T, CAP>();
+        buf[0] = MaybeUninit::new(item);
+        Self { len: 1, buf }
+    }
+    /// Create an [`ArrayVec`] from a [`NonEmpty`] array, which must fit in the
+    /// capacity.
+    /// ```compile_fail
+    /// # use nunny::{array, ArrayVec};
+    /// let v = ArrayVec::<_, 1>::from_array(array![1, 2]); // too big!
+    /// ```
+    pub fn from_array<const N: usize>(src: Array<T, N>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCap::<CAP, N>::FITS;
+        let mut buf = uninit_array::<T, CAP>();
+        for (dst, src) in buf.iter_mut().zip(src) {
+            *dst = MaybeUninit::new(src);
+        }
+        Self { len: N, buf }
+    }
+    /// Create an [`ArrayVec`] by cloning the given [`NonEmpty`] slice, returning
+    /// [`None`] if it does not fit in the capacity.
+    pub fn from_slice(src: &Slice<T>) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let mut it = Self::of(src.first().clone());
+        for item in src.iter().skip(1) {
+            it.try_push(item.clone()).ok()?;
+        }
+        Some(it)
+    }
+
+    ////////////
+    // Utilities
+    ////////////
+
+    /// Returns the fixed capacity.
+    pub const fn capacity(&self) -> NonZeroUsize {
+        // Safety:
+        // - checked at construction
+        unsafe { crate::non_zero_usize(CAP) }
+    }
+    /// Returns `true` if no more elements can be pushed.
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+    /// Returns the [`NonEmpty`] array if the vector is full, or the vector
+    /// itself otherwise.
+    pub fn into_array(self) -> Result<Array<T, CAP>, Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // Safety:
+        // - all `CAP` elements are initialized
+        // - `this` is not dropped, so ownership is transferred
+        // - `CAP` is never zero
+        Ok(unsafe { Array::new_unchecked(ptr::read(this.buf.as_ptr().cast::<[T; CAP]>())) })
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_slice_ne(&self) -> &Slice<T> {
+        // Safety:
+        // - the first `len` elements are initialized
+        // - `len` is never zero
+        unsafe {
+            Slice::new_unchecked(slice::from_raw_parts(
+                self.buf.as_ptr().cast::<T>(),
+                self.len,
+            ))
+        }
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_mut_slice_ne(&mut self) -> &mut Slice<T> {
+        // Safety:
+        // - the first `len` elements are initialized
+        // - `len` is never zero
+        unsafe {
+            Slice::new_mut_unchecked(slice::from_raw_parts_mut(
+                self.buf.as_mut_ptr().cast::<T>(),
+                self.len,
+            ))
+        }
+    }
+
+    //////////////////
+    // Shimmed methods
+    //////////////////
+
+    /// Appends an element to the back of the collection, returning it if
+    /// there is no spare capacity.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        match self.buf.get_mut(self.len) {
+            Some(slot) => {
+                *slot = MaybeUninit::new(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+    /// Removes the last element and returns it, or [`None`] if that would
+    /// leave the collection empty.
+    pub fn pop(&mut self) -> Option<T> {
+        match self.len {
+            1 => None,
+            _ => {
+                self.len -= 1;
+                // Safety:
+                // - element was initialized, and is now outside `len`
+                Some(unsafe { self.buf[self.len].assume_init_read() })
+            }
+        }
+    }
+    /// Shortens the vector to a guaranteed-nonzero length
+    ///
+    /// See [`Vec::truncate`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.truncate).
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        let len = len.get();
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // Safety:
+            // - len < self.len <= CAP
+            unsafe { self.buf.as_mut_ptr().add(len) }.cast::<T>(),
+            self.len - len,
+        );
+        self.len = len;
+        // Safety:
+        // - elements were initialized, and are now outside `len`
+        unsafe { ptr::drop_in_place(tail) }
+    }
+}
+
+/// Known non-empty iterator for [`ArrayVec`].
+impl<T, const CAP: usize> ArrayVec<T, CAP> {
+    pub fn into_iter_ne(self) -> NonEmpty<ArrayVecIntoIter<T, CAP>> {
+        NonEmpty {
+            inner: self.into_iter(),
+        }
+    }
+}
+
+impl<T, const CAP: usize> Drop for ArrayVec<T, CAP> {
+    fn drop(&mut self) {
+        // Safety:
+        // - the first `len` elements are initialized
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const CAP: usize> Clone for ArrayVec<T, CAP>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut buf = uninit_array::<T, CAP>();
+        for (dst, src) in buf.iter_mut().zip(self.iter()) {
+            *dst = MaybeUninit::new(src.clone());
+        }
+        Self { len: self.len, buf }
+    }
+}
+
+impl<T, const CAP: usize> fmt::Debug for ArrayVec<T, CAP>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, const CAP: usize> Hash for ArrayVec<T, CAP>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+/// [`ArrayVec`] to [`Slice`]
+impl<T, const CAP: usize> Deref for ArrayVec<T, CAP> {
+    type Target = Slice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_ne()
+    }
+}
+
+/// [`ArrayVec`] to [`Slice`]
+impl<T, const CAP: usize> DerefMut for ArrayVec<T, CAP> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_ne()
+    }
+}
+
+crate::as_ref_as_mut! {
+    <T, const CAP: usize> for ArrayVec<T, CAP> as [T];
+    <T, const CAP: usize> for ArrayVec<T, CAP> as Slice<T>;
+    <T, const CAP: usize> for ArrayVec<T, CAP> as Self;
+}
+
+crate::borrow_borrow_mut! {
+    <T, const CAP: usize> for ArrayVec<T, CAP> as [T];
+    <T, const CAP: usize> for ArrayVec<T, CAP> as Slice<T>;
+}
+
+crate::slice_iter! {
+    <T, const CAP: usize> for ArrayVec<T, CAP>
+}
+
+/// An iterator that moves out of an [`ArrayVec`].
+pub struct ArrayVecIntoIter<T, const CAP: usize> {
+    alive: Range<usize>,
+    buf: [MaybeUninit<T>; CAP],
+}
+
+impl<T, const CAP: usize> ArrayVecIntoIter<T, CAP> {
+    /// Returns the remaining items of this iterator as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // Safety:
+        // - elements in `alive` are initialized
+        unsafe {
+            slice::from_raw_parts(
+                self.buf.as_ptr().add(self.alive.start).cast::<T>(),
+                self.alive.len(),
+            )
+        }
+    }
+    /// Returns the remaining items of this iterator as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety:
+        // - elements in `alive` are initialized
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.buf.as_mut_ptr().add(self.alive.start).cast::<T>(),
+                self.alive.len(),
+            )
+        }
+    }
+}
+
+impl<T, const CAP: usize> Iterator for ArrayVecIntoIter<T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Safety:
+        // - elements in `alive` are initialized, and are moved out of it
+        self.alive
+            .next()
+            .map(|ix| unsafe { self.buf[ix].assume_init_read() })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const CAP: usize> DoubleEndedIterator for ArrayVecIntoIter<T, CAP> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Safety:
+        // - elements in `alive` are initialized, and are moved out of it
+        self.alive
+            .next_back()
+            .map(|ix| unsafe { self.buf[ix].assume_init_read() })
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for ArrayVecIntoIter<T, CAP> {}
+impl<T, const CAP: usize> FusedIterator for ArrayVecIntoIter<T, CAP> {}
+
+impl<T, const CAP: usize> Drop for ArrayVecIntoIter<T, CAP> {
+    fn drop(&mut self) {
+        // Safety:
+        // - elements in `alive` are initialized
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const CAP: usize> Clone for ArrayVecIntoIter<T, CAP>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut buf = uninit_array::<T, CAP>();
+        for (dst, src) in buf[self.alive.clone()].iter_mut().zip(self.as_slice()) {
+            *dst = MaybeUninit::new(src.clone());
+        }
+        Self {
+            alive: self.alive.clone(),
+            buf,
+        }
+    }
+}
+
+impl<T, const CAP: usize> fmt::Debug for ArrayVecIntoIter<T, CAP>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArrayVecIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, const CAP: usize> IntoIterator for ArrayVec<T, CAP> {
+    type Item = T;
+
+    type IntoIter = ArrayVecIntoIter<T, CAP>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
+        ArrayVecIntoIter {
+            alive: 0..this.len,
+            // Safety:
+            // - `this` is not dropped, so ownership of the elements is transferred
+            buf: unsafe { ptr::read(&this.buf) },
+        }
+    }
+}
+
+impl<T, const CAP: usize> Eq for ArrayVec<T, CAP> where T: Eq {}
+
+mod partial_eq_std {
+    use super::*;
+
+    impl<T, U, const CAP: usize, const CAP2: usize> PartialEq<ArrayVec<U, CAP2>> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &ArrayVec<U, CAP2>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize> PartialEq<[U]> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const N: usize> PartialEq<[U; N]> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize> PartialEq<Slice<U>> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Slice<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const N: usize> PartialEq<Array<U, N>> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Array<U, N>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, U, const CAP: usize> PartialEq<alloc::vec::Vec<U>> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, U, const CAP: usize> PartialEq<crate::Vec<U>> for ArrayVec<T, CAP>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &crate::Vec<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, U, const CAP: usize> PartialEq<ArrayVec<T, CAP>> for [U]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const N: usize> PartialEq<ArrayVec<T, CAP>> for [U; N]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize> PartialEq<ArrayVec<T, CAP>> for Slice<U>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const N: usize> PartialEq<ArrayVec<T, CAP>> for Array<U, N>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, U, const CAP: usize> PartialEq<ArrayVec<T, CAP>> for alloc::vec::Vec<U>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, U, const CAP: usize> PartialEq<ArrayVec<T, CAP>> for crate::Vec<U>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &ArrayVec<T, CAP>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod cmp_std {
+    use super::*;
+
+    impl<T, const CAP: usize> PartialOrd for ArrayVec<T, CAP>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            <[_] as PartialOrd>::partial_cmp(self, other)
+        }
+    }
+    impl<T, const CAP: usize> Ord for ArrayVec<T, CAP>
+    where
+        T: Ord,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            <[_] as Ord>::cmp(self, other)
+        }
+    }
+    impl<T, const CAP: usize> PartialOrd<[T]> for ArrayVec<T, CAP>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, const CAP: usize, const N: usize> PartialOrd<[T; N]> for ArrayVec<T, CAP>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, const CAP: usize> PartialOrd<alloc::vec::Vec<T>> for ArrayVec<T, CAP>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &alloc::vec::Vec<T>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, const CAP: usize> PartialOrd<ArrayVec<T, CAP>> for [T]
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &ArrayVec<T, CAP>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    impl<T, const CAP: usize, const N: usize> PartialOrd<ArrayVec<T, CAP>> for [T; N]
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &ArrayVec<T, CAP>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, const CAP: usize> PartialOrd<ArrayVec<T, CAP>> for alloc::vec::Vec<T>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &ArrayVec<T, CAP>) -> Option<Ordering> {
+            <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+        }
+    }
+}
+
+mod convert_std {
+    use super::*;
+
+    impl<T, const CAP: usize> TryFrom<&Slice<T>> for ArrayVec<T, CAP>
+    where
+        T: Clone,
+    {
+        type Error = crate::TryFromSliceError;
+
+        fn try_from(value: &Slice<T>) -> Result<Self, Self::Error> {
+            Self::from_slice(value).ok_or(crate::TryFromSliceError(()))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<T, const CAP: usize> From<ArrayVec<T, CAP>> for crate::Vec<T> {
+        fn from(value: ArrayVec<T, CAP>) -> Self {
+            value.into_iter_ne().collect_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops() {
+        use core::cell::Cell;
+        struct Counter<'a>(&'a Cell<usize>);
+        impl Drop for Counter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1)
+            }
+        }
+
+        let count = Cell::new(0);
+        let mut v = ArrayVec::<_, 4>::of(Counter(&count));
+        for _ in 0..3 {
+            assert!(v.try_push(Counter(&count)).is_ok());
+        }
+        assert!(v.try_push(Counter(&count)).is_err());
+        assert_eq!(count.get(), 1);
+
+        v.truncate(crate::nonzero!(3));
+        assert_eq!(count.get(), 2);
+        drop(v.pop());
+        assert_eq!(count.get(), 3);
+
+        let mut it = v.into_iter();
+        drop(it.next());
+        assert_eq!(count.get(), 4);
+        drop(it);
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn pop_keeps_one() {
+        let mut v = ArrayVec::<_, 2>::from_array(Array::new([1, 2]).unwrap());
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), None);
+        assert_eq!(v, [1]);
+    }
+}
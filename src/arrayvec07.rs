@@ -0,0 +1,161 @@
+//! Non-empty fixed-capacity vectors, using [`arrayvec`](arrayvec07).
+//!
+//! [`NonEmpty<arrayvec::ArrayVec<T, CAP>>`](NonEmpty) mirrors the API of
+//! [`Vec`](crate::Vec), but like [`arrayvec::ArrayVec`](arrayvec07::ArrayVec),
+//! panics on capacity overflow unless the `try_` methods are used.
+
+use core::num::NonZeroUsize;
+
+use arrayvec07::{ArrayVec, CapacityError, IntoIter};
+
+use crate::{array_vec::AssertCap, Array, NonEmpty};
+
+crate::vec_like! {
+    [T, const CAP: usize] ArrayVec<T, CAP> {
+        Item = T;
+        IntoIter = IntoIter<T, CAP>;
+        name = "array vec";
+        path = "arrayvec07::ArrayVec";
+    }
+}
+
+/// [`arrayvec::ArrayVec`](arrayvec07::ArrayVec) methods
+impl<T, const CAP: usize> NonEmpty<ArrayVec<T, CAP>> {
+    /// Create a [`NonEmpty`] array vec, of a single element.
+    ///
+    /// `CAP` must not be zero.
+    /// ```compile_fail
+    /// # use nunny::NonEmpty;
+    /// let v = NonEmpty::<arrayvec07::ArrayVec<_, 0>>::of("oops"); // no capacity!
+    /// ```
+    pub fn of(item: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCap::<CAP, 1>::NONZERO;
+        let mut inner = ArrayVec::new();
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Returns the inner [`NonEmpty`] array if the vec is full,
+    /// or the vec itself otherwise.
+    pub fn into_inner(self) -> Result<Array<T, CAP>, Self> {
+        match self.into_vec().into_inner() {
+            // Safety:
+            // - full, non-zero capacity
+            Ok(it) => Ok(unsafe { Array::new_unchecked(it) }),
+            // Safety:
+            // - the vec was not modified
+            Err(it) => Err(unsafe { Self::new_unchecked(it) }),
+        }
+    }
+
+    //////////////////
+    // Shimmed methods (rustdoc order)
+    //////////////////
+
+    /// Returns the known non-zero capacity.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(CAP) }
+    }
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.as_vec().is_full()
+    }
+    /// Returns the number of elements that can be pushed.
+    pub fn remaining_capacity(&self) -> usize {
+        self.as_vec().remaining_capacity()
+    }
+    crate::forward_mut! {
+        "arrayvec07::ArrayVec";
+        /// Panics if the vector is full.
+        pub fn push(&mut self, element: T);
+        pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>>;
+        /// Panics if the vector is full.
+        pub fn insert(&mut self, index: usize, element: T);
+        pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>>;
+    }
+    /// See [`try_extend_from_slice`](arrayvec07::ArrayVec::try_extend_from_slice).
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Copy,
+    {
+        // Safety:
+        // - append-only operation
+        unsafe { self.as_mut_vec() }.try_extend_from_slice(other)
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<T, U, const CAP: usize, const CAP2: usize> PartialEq<NonEmpty<ArrayVec<U, CAP2>>>
+        for NonEmpty<ArrayVec<T, CAP>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &NonEmpty<ArrayVec<U, CAP2>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const N: usize> PartialEq<Array<U, N>> for NonEmpty<ArrayVec<T, CAP>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Array<U, N>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const CAP: usize, const CAP2: usize> PartialEq<ArrayVec<U, CAP2>>
+        for NonEmpty<ArrayVec<T, CAP>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &ArrayVec<U, CAP2>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, U, const CAP: usize, const CAP2: usize> PartialEq<NonEmpty<ArrayVec<T, CAP>>>
+        for ArrayVec<U, CAP2>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<ArrayVec<T, CAP>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<T, const CAP: usize> From<Array<T, CAP>> for NonEmpty<ArrayVec<T, CAP>> {
+        fn from(value: Array<T, CAP>) -> Self {
+            // Safety:
+            // - originating array is non-empty by construction
+            unsafe { Self::new_unchecked(ArrayVec::from(value.into_array())) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut v = NonEmpty::<ArrayVec<_, 2>>::of(1);
+        v.push(2);
+        assert!(v.try_push(3).is_err());
+        assert_eq!(v, [1, 2]);
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), None);
+        let collected = v.iter_ne().copied().collect::<NonEmpty<ArrayVec<_, 1>>>();
+        assert_eq!(collected, [1]);
+    }
+}
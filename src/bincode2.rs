@@ -0,0 +1,160 @@
+//! Binary encoding and decoding with [`bincode`](bincode2)'s native traits.
+//!
+//! The encoding is identical to that of the possibly-empty equivalents,
+//! but decoding fails with [`DecodeError::Other`] on empty input,
+//! with the same message as [`Error`](crate::Error).
+//!
+//! `Box<Slice<T>>` is encoded through `bincode`'s implementations for `Box`.
+
+use bincode2::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+use crate::{Array, Slice};
+#[cfg(feature = "alloc")]
+use {crate::Vec, alloc::boxed::Box};
+
+const EMPTY: DecodeError = DecodeError::Other(crate::Error::MESSAGE);
+
+impl<T> Encode for Slice<T>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_slice().encode(encoder)
+    }
+}
+
+impl<'de: 'a, 'a, Context> BorrowDecode<'de, Context> for &'a Slice<u8> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Slice::new(BorrowDecode::borrow_decode(decoder)?).ok_or(EMPTY)
+    }
+}
+
+impl<T, const N: usize> Encode for Array<T, N>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_array().encode(encoder)
+    }
+}
+
+impl<T, const N: usize, Context> Decode<Context> for Array<T, N>
+where
+    T: Decode<Context>,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Array::new(<[T; N]>::decode(decoder)?).ok_or(EMPTY)
+    }
+}
+
+impl<'de, T, const N: usize, Context> BorrowDecode<'de, Context> for Array<T, N>
+where
+    T: BorrowDecode<'de, Context>,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Array::new(<[T; N]>::borrow_decode(decoder)?).ok_or(EMPTY)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Encode for Vec<T>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_slice().encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, Context> Decode<Context> for Vec<T>
+where
+    T: Decode<Context>,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Vec::new(Decode::decode(decoder)?).map_err(|_| EMPTY)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T, Context> BorrowDecode<'de, Context> for Vec<T>
+where
+    T: BorrowDecode<'de, Context>,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Vec::new(BorrowDecode::borrow_decode(decoder)?).map_err(|_| EMPTY)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T, Context> Decode<Context> for Box<Slice<T>>
+where
+    T: Decode<Context>,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Vec::decode(decoder).map(Vec::into_boxed_slice)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T, Context> BorrowDecode<'de, Context> for Box<Slice<T>>
+where
+    T: BorrowDecode<'de, Context>,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Vec::borrow_decode(decoder).map(Vec::into_boxed_slice)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use bincode2::{borrow_decode_from_slice, config::standard, decode_from_slice, encode_to_vec};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let v = Vec::of_extending(1u8, [2]);
+        let bytes = encode_to_vec(&v, standard()).unwrap();
+        assert_eq!(
+            bytes,
+            encode_to_vec([1u8, 2].as_slice(), standard()).unwrap()
+        );
+        let (decoded, _) = decode_from_slice::<Vec<u8>, _>(&bytes, standard()).unwrap();
+        assert_eq!(decoded, v);
+        let (decoded, _) = decode_from_slice::<Box<Slice<u8>>, _>(&bytes, standard()).unwrap();
+        assert_eq!(*decoded, *v);
+        let (decoded, _) = borrow_decode_from_slice::<&Slice<u8>, _>(&bytes, standard()).unwrap();
+        assert_eq!(decoded, &[1, 2]);
+        let (decoded, _) = decode_from_slice::<Array<u8, 2>, _>(&[1, 2], standard()).unwrap();
+        assert_eq!(decoded, [1, 2]);
+
+        let empty = encode_to_vec(<&[u8]>::default(), standard()).unwrap();
+        assert!(matches!(
+            decode_from_slice::<Vec<u8>, _>(&empty, standard()),
+            Err(DecodeError::Other(crate::Error::MESSAGE))
+        ));
+        assert!(matches!(
+            borrow_decode_from_slice::<&Slice<u8>, _>(&empty, standard()),
+            Err(DecodeError::Other(crate::Error::MESSAGE))
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+//! Generators for [`bolero`](bolero013).
+//!
+//! Collections always contain at least one element.
+
+use core::ops::RangeInclusive;
+
+use alloc::boxed::Box;
+use bolero013::{Driver, TypeGenerator, ValueGenerator};
+
+use crate::{Array, Slice, Vec};
+
+const DEFAULT_LEN: RangeInclusive<usize> = 1..=64;
+
+/// Create a generator for [`Vec`]s with elements drawn from `values`.
+///
+/// The length may be customised with [`VecGenerator::len`].
+pub fn vec<G: ValueGenerator>(values: G) -> VecGenerator<G> {
+    VecGenerator {
+        values,
+        len: DEFAULT_LEN,
+    }
+}
+
+/// Generator returned by [`vec()`].
+#[derive(Debug, Clone)]
+#[must_use = "generators do nothing unless used"]
+pub struct VecGenerator<G> {
+    values: G,
+    len: RangeInclusive<usize>,
+}
+
+impl<G> VecGenerator<G> {
+    /// Set the length of generated [`Vec`]s, e.g `1..=n`.
+    ///
+    /// A lower bound of zero is treated as one.
+    ///
+    /// # Panics
+    /// - If `len` only admits a length of zero.
+    pub fn len(self, len: RangeInclusive<usize>) -> Self {
+        let (start, end) = len.into_inner();
+        assert_ne!(end, 0, "len range must admit a non-zero length");
+        Self {
+            values: self.values,
+            len: start.max(1)..=end,
+        }
+    }
+    /// Set the generator for the elements of generated [`Vec`]s.
+    pub fn values<G2: ValueGenerator>(self, values: G2) -> VecGenerator<G2> {
+        VecGenerator {
+            values,
+            len: self.len,
+        }
+    }
+}
+
+impl<G> ValueGenerator for VecGenerator<G>
+where
+    G: ValueGenerator,
+{
+    type Output = Vec<G::Output>;
+
+    fn generate<D: Driver>(&self, driver: &mut D) -> Option<Self::Output> {
+        let len = self.len.generate(driver)?;
+        let mut it = Vec::of(self.values.generate(driver)?);
+        for _ in 1..len {
+            it.push(self.values.generate(driver)?)
+        }
+        Some(it)
+    }
+}
+
+impl<T> TypeGenerator for Vec<T>
+where
+    T: TypeGenerator,
+{
+    fn generate<D: Driver>(driver: &mut D) -> Option<Self> {
+        vec(T::produce()).generate(driver)
+    }
+}
+
+impl<T> TypeGenerator for Box<Slice<T>>
+where
+    T: TypeGenerator,
+{
+    fn generate<D: Driver>(driver: &mut D) -> Option<Self> {
+        Vec::generate(driver).map(Vec::into_boxed_slice)
+    }
+}
+
+impl<T, const N: usize> TypeGenerator for Array<T, N>
+where
+    T: TypeGenerator,
+{
+    fn generate<D: Driver>(driver: &mut D) -> Option<Self> {
+        <[T; N]>::generate(driver).map(Array::new_nonzero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bolero013::driver::{ByteSliceDriver, Options};
+
+    use super::*;
+
+    #[test]
+    fn len() {
+        let generator = vec(u8::produce()).len(0..=2);
+        let options = Options::default();
+        for byte in 0..=u8::MAX {
+            let input = [byte; 16];
+            let mut driver = ByteSliceDriver::new(&input, &options);
+            if let Some(it) = generator.generate(&mut driver) {
+                assert!(it.len_ne().get() <= 2);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic = "len range must admit a non-zero length"]
+    fn empty_len() {
+        let _ = vec(u8::produce()).len(0..=0);
+    }
+}
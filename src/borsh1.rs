@@ -0,0 +1,169 @@
+//! Binary serialization with [`borsh`](borsh1).
+//!
+//! The encoding is identical to that of the possibly-empty equivalents,
+//! but deserialization rejects empty input.
+//!
+//! `Box<Slice<T>>` is supported through `borsh`'s implementations for `Box`.
+//!
+//! [`BorshSchema`](borsh1::BorshSchema) implementations require the
+//! `borsh1-unstable-schema` feature, since `borsh`'s schema API is unstable.
+
+use borsh1::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::{Array, Slice, Vec};
+
+fn empty() -> Error {
+    Error::new(ErrorKind::InvalidData, crate::Error::MESSAGE)
+}
+
+impl<T> BorshSerialize for Slice<T>
+where
+    T: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_slice().serialize(writer)
+    }
+}
+
+impl<T, const N: usize> BorshSerialize for Array<T, N>
+where
+    T: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_array().serialize(writer)
+    }
+}
+
+impl<T, const N: usize> BorshDeserialize for Array<T, N>
+where
+    T: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Array::new(<[T; N]>::deserialize_reader(reader)?).ok_or_else(empty)
+    }
+}
+
+impl<T> BorshSerialize for Vec<T>
+where
+    T: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_vec().serialize(writer)
+    }
+}
+
+impl<T> BorshDeserialize for Vec<T>
+where
+    T: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Vec::new(alloc::vec::Vec::deserialize_reader(reader)?).map_err(|_| empty())
+    }
+}
+
+#[cfg(feature = "borsh1-unstable-schema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh1-unstable-schema")))]
+mod schema {
+    use alloc::{collections::BTreeMap, format};
+
+    use borsh1::{
+        schema::{add_definition, Declaration, Definition},
+        BorshSchema,
+    };
+
+    use crate::{Array, Slice, Vec};
+
+    /// Sequences are length-prefixed, with a length of at least one.
+    fn add_sequence<T: BorshSchema>(
+        declaration: Declaration,
+        definitions: &mut BTreeMap<Declaration, Definition>,
+    ) {
+        let definition = Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: 1..=*Definition::DEFAULT_LENGTH_RANGE.end(),
+            elements: T::declaration(),
+        };
+        add_definition(declaration, definition, definitions);
+        T::add_definitions_recursively(definitions);
+    }
+
+    impl<T> BorshSchema for Slice<T>
+    where
+        T: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_sequence::<T>(Self::declaration(), definitions)
+        }
+
+        fn declaration() -> Declaration {
+            format!("nunny::Vec<{}>", T::declaration())
+        }
+    }
+
+    /// [`Array`]s are fixed-size, so have the same schema as `[T; N]`.
+    impl<T, const N: usize> BorshSchema for Array<T, N>
+    where
+        T: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            <[T; N]>::add_definitions_recursively(definitions)
+        }
+
+        fn declaration() -> Declaration {
+            <[T; N]>::declaration()
+        }
+    }
+
+    impl<T> BorshSchema for Vec<T>
+    where
+        T: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            Slice::<T>::add_definitions_recursively(definitions)
+        }
+
+        fn declaration() -> Declaration {
+            Slice::<T>::declaration()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let v = Vec::of(1u8);
+        let bytes = borsh1::to_vec(&v).unwrap();
+        assert_eq!(bytes, borsh1::to_vec(&std::vec![1u8]).unwrap());
+        assert_eq!(borsh1::from_slice::<Vec<u8>>(&bytes).unwrap(), v);
+        assert_eq!(*borsh1::from_slice::<Box<Slice<u8>>>(&bytes).unwrap(), *v);
+        assert_eq!(borsh1::from_slice::<Array<u8, 1>>(&[1]).unwrap(), [1]);
+
+        let empty = borsh1::to_vec(&std::vec::Vec::<u8>::new()).unwrap();
+        assert!(borsh1::from_slice::<Vec<u8>>(&empty).is_err());
+    }
+
+    #[cfg(feature = "borsh1-unstable-schema")]
+    #[test]
+    fn schema() {
+        use borsh1::{schema::Definition, BorshSchema as _};
+
+        let mut definitions = Default::default();
+        Vec::<u8>::add_definitions_recursively(&mut definitions);
+        assert_eq!(
+            definitions[&Vec::<u8>::declaration()],
+            Definition::Sequence {
+                length_width: 4,
+                length_range: 1..=u32::MAX as u64,
+                elements: "u8".into()
+            }
+        );
+    }
+}
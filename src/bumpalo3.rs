@@ -0,0 +1,455 @@
+//! Non-empty vectors allocated in a [`bumpalo::Bump`](bumpalo3::Bump) arena.
+
+use core::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use bumpalo3::{
+    collections::{vec::IntoIter, Vec},
+    Bump,
+};
+
+use crate::{Error, NonEmpty, Slice};
+
+impl<T> Eq for NonEmpty<Vec<'_, T>> where T: Eq {}
+
+/// [`bumpalo::collections::Vec`](bumpalo3::collections::Vec) methods
+impl<'bump, T> NonEmpty<Vec<'bump, T>> {
+    ///////////
+    // Creation
+    ///////////
+
+    /// Create a new [`NonEmpty`] arena-allocated vec, returning the original
+    /// allocation if it was empty.
+    pub fn new(src: Vec<'bump, T>) -> Result<Self, Vec<'bump, T>> {
+        match src.is_empty() {
+            false => Ok(unsafe { Self::new_unchecked(src) }),
+            true => Err(src),
+        }
+    }
+    /// Create a new [`NonEmpty`] arena-allocated vec
+    ///
+    /// Returns [`None`] if `src` is empty.
+    pub fn new_ref<'a>(src: &'a Vec<'bump, T>) -> Option<&'a Self> {
+        match src.is_empty() {
+            true => None,
+            // Safety
+            // - checked non empty
+            false => Some(unsafe { Self::new_ref_unchecked(src) }),
+        }
+    }
+    /// Create a new [`NonEmpty`] arena-allocated vec
+    ///
+    /// Returns [`None`] if `src` is empty.
+    pub fn new_mut<'a>(src: &'a mut Vec<'bump, T>) -> Option<&'a mut Self> {
+        match src.is_empty() {
+            true => None,
+            // Safety
+            // - checked non empty
+            false => Some(unsafe { Self::new_mut_unchecked(src) }),
+        }
+    }
+    /// Create a new [`NonEmpty`] arena-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_unchecked(src: Vec<'bump, T>) -> Self {
+        debug_assert!(!src.is_empty());
+        Self { inner: src }
+    }
+    /// Create a new [`NonEmpty`] arena-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_ref_unchecked<'a>(src: &'a Vec<'bump, T>) -> &'a Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &*(src as *const Vec<'bump, T> as *const Self) }
+    }
+    /// Create a new [`NonEmpty`] arena-allocated vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_mut_unchecked<'a>(src: &'a mut Vec<'bump, T>) -> &'a mut Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &mut *(src as *mut Vec<'bump, T> as *mut Self) }
+    }
+
+    ////////////
+    // Utilities
+    ////////////
+
+    /// Create a [`NonEmpty`] arena-allocated vec, of a single element.
+    pub fn of_in(item: T, bump: &'bump Bump) -> Self {
+        Self::of_with_capacity_in(item, 1, bump)
+    }
+    /// Create a [`NonEmpty`] arena-allocated vec, of a single element, with
+    /// capacity for `capacity` elements without (re)-allocating.
+    pub fn of_with_capacity_in(item: T, capacity: usize, bump: &'bump Bump) -> Self {
+        let mut inner = Vec::with_capacity_in(capacity, bump);
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Create a [`NonEmpty`] arena-allocated vec with `len` items, filled with
+    /// [`Clone`]s of the given `value`.
+    pub fn filled_in(value: T, len: NonZeroUsize, bump: &'bump Bump) -> Self
+    where
+        T: Clone,
+    {
+        let mut inner = Vec::new_in(bump);
+        inner.resize(len.get(), value);
+        // Safety:
+        // - len is nonzero
+        unsafe { Self::new_unchecked(inner) }
+    }
+    fn check(&self) {
+        debug_assert_ne!(self.inner.len(), 0)
+    }
+
+    /// Returns a [`bumpalo::collections::Vec`](bumpalo3::collections::Vec).
+    pub fn as_vec(&self) -> &Vec<'bump, T> {
+        self.check();
+        &self.inner
+    }
+    /// Returns a [`bumpalo::collections::Vec`](bumpalo3::collections::Vec).
+    ///
+    /// # Safety
+    /// - returned vec must not be emptied through this reference
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<'bump, T> {
+        self.check();
+        &mut self.inner
+    }
+    /// Returns a [`bumpalo::collections::Vec`](bumpalo3::collections::Vec).
+    pub fn into_vec(self) -> Vec<'bump, T> {
+        let Self { inner } = self;
+        inner
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_slice_ne(&self) -> &Slice<T> {
+        unsafe { Slice::new_unchecked(self.as_vec()) }
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_mut_slice_ne(&mut self) -> &mut Slice<T> {
+        unsafe { Slice::new_mut_unchecked(self.as_mut_vec()) }
+    }
+    /// Returns the arena this vec allocates in.
+    pub fn bump(&self) -> &'bump Bump {
+        self.as_vec().bump()
+    }
+
+    //////////////////
+    // Shimmed methods
+    //////////////////
+
+    /// Returns the known non-zero length.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+
+    crate::forward_mut! {
+        "bumpalo3::collections::Vec";
+        pub fn reserve(&mut self, additional: usize);
+        pub fn reserve_exact(&mut self, additional: usize);
+        pub fn shrink_to_fit(&mut self);
+    }
+
+    /// Returns a [`NonEmpty`] slice which lives as long as the arena.
+    ///
+    /// This is the arena equivalent of [`Vec::leak`](crate::Vec::leak).
+    ///
+    /// See [`into_bump_slice_mut`](bumpalo3::collections::Vec::into_bump_slice_mut).
+    pub fn into_bump_slice(self) -> &'bump mut Slice<T> {
+        let inner = self.into_vec().into_bump_slice_mut();
+        // Safety:
+        // - originating slice is non-empty by construction
+        unsafe { Slice::new_mut_unchecked(inner) }
+    }
+
+    /// Shortens the vector to a guaranteed-nonzero length
+    ///
+    /// See [`truncate`](bumpalo3::collections::Vec::truncate).
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        // Safety:
+        // - len is not zero, so vector will not be emptied
+        unsafe { self.as_mut_vec() }.truncate(len.get());
+        self.check();
+    }
+
+    crate::forward_mut! {
+        "bumpalo3::collections::Vec";
+        pub fn insert(&mut self, index: usize, element: T);
+        pub fn push(&mut self, value: T);
+        pub fn append(&mut self, other: &mut Vec<'bump, T>);
+    }
+
+    /// See [`dedup_by_key`](bumpalo3::collections::Vec::dedup_by_key).
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by_key(key);
+        self.check();
+    }
+    /// See [`dedup_by`](bumpalo3::collections::Vec::dedup_by).
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by(same_bucket);
+        self.check();
+    }
+}
+
+/// Known non-empty iterator for [`NonEmpty<bumpalo::collections::Vec>`](NonEmpty).
+impl<'bump, T> NonEmpty<Vec<'bump, T>> {
+    pub fn into_iter_ne(self) -> NonEmpty<IntoIter<'bump, T>> {
+        NonEmpty {
+            inner: self.into_vec().into_iter(),
+        }
+    }
+}
+
+/// Collecting into an arena.
+impl<I> NonEmpty<I>
+where
+    I: Iterator,
+{
+    /// Collect this iterator into a [`NonEmpty<bumpalo::collections::Vec>`](NonEmpty)
+    /// in the given arena.
+    /// ```
+    /// # use nunny::slice;
+    /// let bump = bumpalo3::Bump::new();
+    /// let v = slice![1, 2, 3].iter_ne().copied().collect_in(&bump);
+    /// assert_eq!(v.last(), &3);
+    /// ```
+    pub fn collect_in(self, bump: &Bump) -> NonEmpty<Vec<'_, I::Item>> {
+        // Safety:
+        // - NonEmpty<impl Iterator> is only constructed from known NonEmpty items
+        // - NonEmpty<impl Iterator> does not allow mutable access to the inner iterator
+        //   (so it always has one element)
+        unsafe { NonEmpty::<Vec<_>>::new_unchecked(Vec::from_iter_in(self.inner, bump)) }
+    }
+}
+
+impl<T> Deref for NonEmpty<Vec<'_, T>> {
+    type Target = Slice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_ne()
+    }
+}
+
+impl<T> DerefMut for NonEmpty<Vec<'_, T>> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_ne()
+    }
+}
+
+crate::as_ref_as_mut! {
+    <T> for NonEmpty<Vec<'_, T>> as [T];
+    <T> for NonEmpty<Vec<'_, T>> as Slice<T>;
+}
+
+crate::borrow_borrow_mut! {
+    <T> for NonEmpty<Vec<'_, T>> as [T];
+    <T> for NonEmpty<Vec<'_, T>> as Slice<T>;
+}
+
+mod iter {
+    use super::*;
+
+    impl<'bump, T> IntoIterator for NonEmpty<Vec<'bump, T>> {
+        type Item = T;
+
+        type IntoIter = IntoIter<'bump, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.into_vec().into_iter()
+        }
+    }
+    impl<'a, T> IntoIterator for &'a NonEmpty<Vec<'_, T>> {
+        type Item = &'a T;
+        type IntoIter = core::slice::Iter<'a, T>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+    impl<'a, T> IntoIterator for &'a mut NonEmpty<Vec<'_, T>> {
+        type Item = &'a mut T;
+        type IntoIter = core::slice::IterMut<'a, T>;
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+    impl<'a, T> Extend<&'a T> for NonEmpty<Vec<'_, T>>
+    where
+        T: Copy + 'a,
+    {
+        fn extend<II: IntoIterator<Item = &'a T>>(&mut self, iter: II) {
+            // Safety:
+            // - append-only operation
+            unsafe { self.as_mut_vec() }.extend(iter)
+        }
+    }
+    impl<T> Extend<T> for NonEmpty<Vec<'_, T>> {
+        fn extend<II: IntoIterator<Item = T>>(&mut self, iter: II) {
+            // Safety:
+            // - append-only operation
+            unsafe { self.as_mut_vec() }.extend(iter)
+        }
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<T, U> PartialEq<NonEmpty<Vec<'_, U>>> for NonEmpty<Vec<'_, T>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<'_, U>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U> PartialEq<[U]> for NonEmpty<Vec<'_, T>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<[U; N]> for NonEmpty<Vec<'_, T>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U> PartialEq<Slice<U>> for NonEmpty<Vec<'_, T>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Slice<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, U> PartialEq<NonEmpty<Vec<'_, T>>> for [U]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<'_, T>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<NonEmpty<Vec<'_, T>>> for [U; N]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<'_, T>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U> PartialEq<NonEmpty<Vec<'_, T>>> for Slice<U>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<'_, T>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod cmp {
+    use super::*;
+
+    impl<T> PartialOrd for NonEmpty<Vec<'_, T>>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            <[_] as PartialOrd>::partial_cmp(self, other)
+        }
+    }
+    impl<T> Ord for NonEmpty<Vec<'_, T>>
+    where
+        T: Ord,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            <[_] as Ord>::cmp(self, other)
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<'bump, T> TryFrom<Vec<'bump, T>> for NonEmpty<Vec<'bump, T>> {
+        type Error = Vec<'bump, T>;
+
+        fn try_from(value: Vec<'bump, T>) -> Result<Self, Self::Error> {
+            Self::new(value)
+        }
+    }
+    impl<'a, 'bump, T> TryFrom<&'a Vec<'bump, T>> for &'a NonEmpty<Vec<'bump, T>> {
+        type Error = Error;
+
+        fn try_from(value: &'a Vec<'bump, T>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<'bump, T>>::new_ref(value).ok_or(Error(()))
+        }
+    }
+    impl<'a, 'bump, T> TryFrom<&'a mut Vec<'bump, T>> for &'a mut NonEmpty<Vec<'bump, T>> {
+        type Error = Error;
+
+        fn try_from(value: &'a mut Vec<'bump, T>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<'bump, T>>::new_mut(value).ok_or(Error(()))
+        }
+    }
+    impl<'bump, T> From<NonEmpty<Vec<'bump, T>>> for Vec<'bump, T> {
+        fn from(value: NonEmpty<Vec<'bump, T>>) -> Self {
+            value.into_vec()
+        }
+    }
+    impl<'bump, T> From<NonEmpty<Vec<'bump, T>>> for &'bump mut Slice<T> {
+        fn from(value: NonEmpty<Vec<'bump, T>>) -> Self {
+            value.into_bump_slice()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_bump_slice() {
+        let bump = Bump::new();
+        let mut v = NonEmpty::<Vec<_>>::of_in(1, &bump);
+        v.push(2);
+        v.extend([3]);
+        assert_eq!(v, [1, 2, 3]);
+        let borrowed: &Slice<i32> = core::borrow::Borrow::borrow(&v);
+        assert_eq!(borrowed, AsRef::<[i32]>::as_ref(&v));
+        let slice: &mut Slice<i32> = v.into_bump_slice();
+        *slice.first_mut() = 0;
+        assert_eq!(slice, &[0, 2, 3]);
+    }
+}
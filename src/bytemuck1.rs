@@ -0,0 +1,125 @@
+//! Casting with [`bytemuck`](bytemuck1).
+//!
+//! [`Array`] is only [`Zeroable`], [`Pod`] and [`TransparentWrapper`] for
+//! specific non-zero lengths, since `[T; 0]` must not be reinterpreted as
+//! a [`NonEmpty`](crate::NonEmpty).
+//! These are the non-zero lengths which `bytemuck` implements [`Pod`] for
+//! arrays of without its `min_const_generics` feature:
+//! `1..=32`, `48`, `64`, `96`, `128`, `256`, `512`, `1024`, `2048` and `4096`.
+//! See [`bytemuck`'s `Pod` implementations](bytemuck1::Pod#foreign-impls).
+
+use bytemuck1::{AnyBitPattern, NoUninit, Pod, PodCastError, TransparentWrapper, Zeroable};
+
+use crate::{Array, Slice};
+
+impl<T> Slice<T> {
+    /// Reinterpret the elements of this slice as another type.
+    ///
+    /// See [`bytemuck::cast_slice`](bytemuck1::cast_slice).
+    ///
+    /// # Panics
+    /// - If [`Self::try_cast_slice`] would fail.
+    pub fn cast_slice<U>(&self) -> &Slice<U>
+    where
+        T: NoUninit,
+        U: AnyBitPattern,
+    {
+        match self.try_cast_slice() {
+            Ok(it) => it,
+            Err(e) => panic!("cast_slice>{:?}", e),
+        }
+    }
+    /// Reinterpret the elements of this slice as another type.
+    ///
+    /// See [`bytemuck::try_cast_slice`](bytemuck1::try_cast_slice).
+    ///
+    /// Fails with [`PodCastError::OutputSliceWouldHaveSlop`] unless the length
+    /// in bytes is a multiple of the size of `U`,
+    /// and with [`PodCastError::SizeMismatch`] if the result would be empty
+    /// (for example, when `T` is zero-sized, but `U` is not).
+    pub fn try_cast_slice<U>(&self) -> Result<&Slice<U>, PodCastError>
+    where
+        T: NoUninit,
+        U: AnyBitPattern,
+    {
+        Slice::new(bytemuck1::try_cast_slice(self.as_slice())?).ok_or(PodCastError::SizeMismatch)
+    }
+    /// Reinterpret the elements of this slice as another type.
+    ///
+    /// See [`bytemuck::cast_slice_mut`](bytemuck1::cast_slice_mut).
+    ///
+    /// # Panics
+    /// - If [`Self::try_cast_slice_mut`] would fail.
+    pub fn cast_slice_mut<U>(&mut self) -> &mut Slice<U>
+    where
+        T: NoUninit + AnyBitPattern,
+        U: NoUninit + AnyBitPattern,
+    {
+        match self.try_cast_slice_mut() {
+            Ok(it) => it,
+            Err(e) => panic!("cast_slice_mut>{:?}", e),
+        }
+    }
+    /// Reinterpret the elements of this slice as another type.
+    ///
+    /// See [`Self::try_cast_slice`].
+    pub fn try_cast_slice_mut<U>(&mut self) -> Result<&mut Slice<U>, PodCastError>
+    where
+        T: NoUninit + AnyBitPattern,
+        U: NoUninit + AnyBitPattern,
+    {
+        Slice::new_mut(bytemuck1::try_cast_slice_mut(self.as_mut_slice())?)
+            .ok_or(PodCastError::SizeMismatch)
+    }
+}
+
+macro_rules! impl_for_lengths {
+    ($($n:literal)*) => {
+        $(
+            // Safety
+            // - #[repr(transparent)]
+            // - the length is non-zero
+            unsafe impl<T: Zeroable> Zeroable for Array<T, $n> {}
+            unsafe impl<T: Pod> Pod for Array<T, $n> {}
+            unsafe impl<T> TransparentWrapper<[T; $n]> for Array<T, $n> {}
+        )*
+    };
+}
+
+impl_for_lengths! {
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+    48 64 96 128 256 512 1024 2048 4096
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast() {
+        let bytes = Slice::new(&[1u8, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let words = bytes.cast_slice::<[u8; 4]>();
+        assert_eq!(words.as_slice(), [[1, 2, 3, 4], [5, 6, 7, 8]]);
+        assert_eq!(words.cast_slice::<u8>(), bytes);
+        assert_eq!(
+            Slice::new(&[1u8, 2, 3])
+                .unwrap()
+                .try_cast_slice::<[u8; 2]>(),
+            Err(PodCastError::OutputSliceWouldHaveSlop)
+        );
+        assert_eq!(
+            Slice::new(&[()]).unwrap().try_cast_slice::<u8>(),
+            Err(PodCastError::SizeMismatch)
+        );
+    }
+
+    #[test]
+    fn array() {
+        let array: Array<u8, 4> = bytemuck1::cast(0x01020304u32.to_be_bytes());
+        assert_eq!(array, [1, 2, 3, 4]);
+        assert_eq!(Array::<u16, 2>::zeroed(), [0, 0]);
+        assert_eq!(Array::<u8, 1>::wrap([1]), [1]);
+        let _: &Array<u8, 2> = TransparentWrapper::wrap_ref(&[1, 2]);
+    }
+}
@@ -0,0 +1,235 @@
+//! Argument parsing with [`clap`](clap4).
+//!
+//! `clap` parses each value of an argument on its own,
+//! so a [`Vec`] is parsed from a single delimited value,
+//! e.g `--hosts a,b,c`, rather than from multiple values like `num_args(1..)`.
+//! This is documented on the public [`crate::clap`] module.
+//! See [`VecValueParser`] for using it with `clap`'s derive macros.
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+use std::ffi::OsStr;
+
+use clap4::{
+    builder::{MapValueParser, TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+
+use crate::{Delimited, ParseDelimitedError, Vec};
+
+/// Parser for [`Vec`]s, returned by [`ValueParserFactory::value_parser`].
+///
+/// Elements are separated by `SEP`, and parsed with [`FromStr`].
+/// [`Vec<T>`] uses a comma, and [`Delimited<T, SEP>`] uses `SEP`.
+/// See [`Vec::parse_delimited`].
+///
+/// With `#[derive(Parser)]`, the argument takes one value, and is required:
+/// ```
+/// # use clap4 as clap;
+/// # use clap::Parser;
+/// #[derive(Parser)]
+/// struct Args {
+///     #[arg(long)]
+///     hosts: nunny::Vec<String>,
+///     #[arg(long)]
+///     path: nunny::Delimited<String, ':'>,
+/// }
+///
+/// let args = Args::parse_from(["app", "--hosts", "a,b", "--path", "/bin:/usr/bin"]);
+/// assert_eq!(args.hosts, ["a", "b"]);
+/// assert_eq!(args.path.0, ["/bin", "/usr/bin"]);
+/// ```
+///
+/// `clap` treats any field type called `Vec` as a [`std::vec::Vec`],
+/// so the type must be written with its path, like `nunny::Vec<T>` above,
+/// or as `NonEmpty<Vec<T>>`:
+/// ```compile_fail
+/// # use clap4 as clap;
+/// # use clap::Parser;
+/// use nunny::Vec;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[arg(long)]
+///     hosts: Vec<String>,
+/// }
+/// ```
+///
+/// Since each occurrence of the argument is parsed as a whole [`Vec`],
+/// the argument takes one value, and may only be given once.
+/// See [the module documentation](crate::clap) for accepting multiple values.
+pub struct VecValueParser<T, const SEP: char = ','> {
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T, const SEP: char> VecValueParser<T, SEP> {
+    /// Create a new parser.
+    pub const fn new() -> Self {
+        Self { _item: PhantomData }
+    }
+}
+
+impl<T, const SEP: char> Default for VecValueParser<T, SEP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SEP: char> Clone for VecValueParser<T, SEP> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SEP: char> fmt::Debug for VecValueParser<T, SEP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecValueParser")
+            .field("sep", &SEP)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, const SEP: char> TypedValueParser for VecValueParser<T, SEP>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: fmt::Display,
+{
+    type Value = Vec<T>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let arg = match arg {
+            Some(arg) => arg.to_string(),
+            None => String::from("..."),
+        };
+        let Some(value) = value.to_str() else {
+            return Err(Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+        Vec::parse_delimited(value, SEP).map_err(|e| match e {
+            ParseDelimitedError::Empty => Error::raw(
+                ErrorKind::InvalidValue,
+                format!("a value is required for '{arg}' but none was supplied\n"),
+            )
+            .with_cmd(cmd),
+            e => Error::raw(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{arg}': {e}\n"),
+            )
+            .with_cmd(cmd),
+        })
+    }
+}
+
+impl<T> ValueParserFactory for Vec<T>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: fmt::Display,
+{
+    type Parser = VecValueParser<T>;
+
+    fn value_parser() -> Self::Parser {
+        VecValueParser::new()
+    }
+}
+
+impl<T, const SEP: char> ValueParserFactory for Delimited<T, SEP>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: fmt::Display,
+{
+    type Parser = MapValueParser<VecValueParser<T, SEP>, fn(Vec<T>) -> Self>;
+
+    fn value_parser() -> Self::Parser {
+        VecValueParser::new().map(Delimited as fn(_) -> _)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap4::value_parser;
+
+    use super::*;
+
+    fn command() -> Command {
+        Command::new("app").arg(
+            Arg::new("ports")
+                .long("ports")
+                .required(true)
+                .value_parser(value_parser!(Vec<u16>)),
+        )
+    }
+
+    #[test]
+    fn parse() {
+        let matches = command().get_matches_from(["app", "--ports", "80,443"]);
+        let ports = matches.get_one::<Vec<u16>>("ports").unwrap();
+        assert_eq!(ports, &[80, 443]);
+
+        for args in [
+            &["app"][..],
+            &["app", "--ports", ""],
+            &["app", "--ports", "80,"],
+            &["app", "--ports", "http"],
+        ] {
+            assert!(command().try_get_matches_from(args).is_err());
+        }
+    }
+
+    mod derive {
+        use clap4 as clap;
+        use clap4::{error::ErrorKind, Parser};
+
+        use crate::{Delimited, NonEmpty};
+
+        #[derive(Parser)]
+        struct Args {
+            #[arg(long)]
+            hosts: crate::Vec<String>,
+            #[arg(long)]
+            ports: NonEmpty<std::vec::Vec<u16>>,
+            #[arg(long)]
+            path: Option<Delimited<String, ':'>>,
+        }
+
+        #[test]
+        fn derive() {
+            let args = Args::parse_from(["app", "--hosts", "a,b", "--ports", "80"]);
+            assert_eq!(args.hosts, ["a", "b"]);
+            assert_eq!(args.ports, [80]);
+            assert_eq!(args.path, None);
+
+            let args = Args::parse_from(["app", "--hosts=a", "--ports=80", "--path=/bin:/usr/bin"]);
+            assert_eq!(args.path.unwrap().0, ["/bin", "/usr/bin"]);
+
+            for (args, kind) in [
+                (
+                    &["app", "--ports", "80"][..],
+                    ErrorKind::MissingRequiredArgument,
+                ),
+                (
+                    &["app", "--hosts", "a", "--ports", ""],
+                    ErrorKind::InvalidValue,
+                ),
+                (
+                    &["app", "--hosts", "a", "--ports", "x"],
+                    ErrorKind::ValueValidation,
+                ),
+                (
+                    &["app", "--hosts", "a", "--hosts", "b", "--ports", "80"],
+                    ErrorKind::ArgumentConflict,
+                ),
+                (
+                    &["app", "--hosts", "a", "b", "--ports", "80"],
+                    ErrorKind::UnknownArgument,
+                ),
+            ] {
+                let e = Args::try_parse_from(args).err().unwrap();
+                assert_eq!(e.kind(), kind);
+            }
+        }
+    }
+}
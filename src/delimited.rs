@@ -0,0 +1,268 @@
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+use crate::Slice;
+#[cfg(feature = "alloc")]
+use crate::Vec;
+
+/// [`Display`](fmt::Display)s each element, separated by `sep`.
+///
+/// Formatting options such as precision are applied to each element.
+///
+/// Returned by [`Slice::display_joined`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayJoined<'a, T, S> {
+    slice: &'a Slice<T>,
+    sep: S,
+}
+
+impl<T, S> fmt::Display for DisplayJoined<'_, T, S>
+where
+    T: fmt::Display,
+    S: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, rest) = self.slice.split_first();
+        first.fmt(f)?;
+        for it in rest {
+            // formatting options only apply to the elements
+            write!(f, "{}", self.sep)?;
+            it.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Slice<T> {
+    /// Returns an adapter which [`Display`](fmt::Display)s each element,
+    /// separated by `sep`, without allocating.
+    /// ```
+    /// # use nunny::slice;
+    /// assert_eq!(slice![1, 2, 3].display_joined(", ").to_string(), "1, 2, 3");
+    /// ```
+    pub fn display_joined<S>(&self, sep: S) -> DisplayJoined<'_, T, S> {
+        DisplayJoined { slice: self, sep }
+    }
+}
+
+/// Error returned when parsing a delimited list fails.
+///
+/// See [`Vec::parse_delimited`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDelimitedError<E> {
+    /// The input was empty.
+    Empty,
+    /// The item at `index` was empty.
+    EmptyItem {
+        /// Zero-based position of the item.
+        index: usize,
+    },
+    /// The item at `index` could not be parsed.
+    Item {
+        /// Zero-based position of the item.
+        index: usize,
+        /// The error from parsing the item.
+        error: E,
+    },
+}
+
+impl<E> fmt::Display for ParseDelimitedError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("input was empty"),
+            Self::EmptyItem { index } => write!(f, "item {} was empty", index),
+            Self::Item { index, error } => write!(f, "invalid item {}: {}", index, error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ParseDelimitedError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Empty | Self::EmptyItem { .. } => None,
+            Self::Item { error, .. } => Some(error),
+        }
+    }
+}
+
+/// What to do with empty items (e.g in `a,,b` or `a,`) when parsing a
+/// delimited list.
+///
+/// See [`Vec::parse_delimited_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EmptyItems {
+    /// Fail with [`ParseDelimitedError::EmptyItem`].
+    #[default]
+    Reject,
+    /// Leave them out of the list.
+    Skip,
+    /// Parse them like any other item.
+    Keep,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Vec<T> {
+    /// Parse each item of `s`, separated by `sep`.
+    ///
+    /// Empty input, and empty items (e.g `a,,b` or `a,`) are rejected.
+    /// See [`Self::parse_delimited_with`] to skip or keep empty items instead.
+    /// ```
+    /// # use nunny::{ParseDelimitedError, Vec};
+    /// let peers = Vec::<u16>::parse_delimited("80,443", ',').unwrap();
+    /// assert_eq!(peers, [80, 443]);
+    ///
+    /// assert_eq!(Vec::<u16>::parse_delimited("", ','), Err(ParseDelimitedError::Empty));
+    /// assert_eq!(
+    ///     Vec::<u16>::parse_delimited("80,", ','),
+    ///     Err(ParseDelimitedError::EmptyItem { index: 1 })
+    /// );
+    /// ```
+    pub fn parse_delimited(s: &str, sep: char) -> Result<Self, ParseDelimitedError<T::Err>>
+    where
+        T: FromStr,
+    {
+        Self::parse_delimited_with(s, sep, EmptyItems::Reject)
+    }
+    /// Parse each item of `s`, separated by `sep`,
+    /// handling empty items according to `empty`.
+    ///
+    /// Empty input is always rejected, as is input where every item is skipped.
+    /// Indices in errors count every item, including skipped ones.
+    /// ```
+    /// # use nunny::{EmptyItems, ParseDelimitedError, Vec};
+    /// let peers = Vec::<String>::parse_delimited_with("a,,b,", ',', EmptyItems::Skip).unwrap();
+    /// assert_eq!(peers, ["a", "b"]);
+    ///
+    /// let fields = Vec::<String>::parse_delimited_with("a,,b", ',', EmptyItems::Keep).unwrap();
+    /// assert_eq!(fields, ["a", "", "b"]);
+    ///
+    /// assert_eq!(
+    ///     Vec::<String>::parse_delimited_with(",", ',', EmptyItems::Skip),
+    ///     Err(ParseDelimitedError::Empty)
+    /// );
+    /// ```
+    pub fn parse_delimited_with(
+        s: &str,
+        sep: char,
+        empty: EmptyItems,
+    ) -> Result<Self, ParseDelimitedError<T::Err>>
+    where
+        T: FromStr,
+    {
+        if s.is_empty() {
+            return Err(ParseDelimitedError::Empty);
+        }
+        let mut items = s
+            .split(sep)
+            .enumerate()
+            .filter(|(_, item)| !(item.is_empty() && empty == EmptyItems::Skip))
+            .map(|(index, item)| match item {
+                "" if empty == EmptyItems::Reject => Err(ParseDelimitedError::EmptyItem { index }),
+                item => item
+                    .parse()
+                    .map_err(|error| ParseDelimitedError::Item { index, error }),
+            });
+        let mut it = match items.next() {
+            Some(first) => Vec::of(first?),
+            None => return Err(ParseDelimitedError::Empty),
+        };
+        for item in items {
+            it.push(item?)
+        }
+        Ok(it)
+    }
+}
+
+/// A [`Vec`] which is parsed from and [`Display`](fmt::Display)ed as a list
+/// separated by `SEP`.
+///
+/// Empty items are rejected.
+/// See [`Vec::parse_delimited`], and [`Vec::parse_delimited_with`] for other
+/// [`EmptyItems`] policies.
+/// ```
+/// # use nunny::Delimited;
+/// let peers = "a,b,c".parse::<Delimited<String>>().unwrap();
+/// assert_eq!(peers.0, ["a", "b", "c"]);
+/// assert_eq!(peers.to_string(), "a,b,c");
+///
+/// let path = "/bin:/usr/bin".parse::<Delimited<String, ':'>>().unwrap();
+/// assert_eq!(path.0.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Delimited<T, const SEP: char = ','>(pub Vec<T>);
+
+#[cfg(feature = "alloc")]
+impl<T, const SEP: char> From<Vec<T>> for Delimited<T, SEP> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const SEP: char> FromStr for Delimited<T, SEP>
+where
+    T: FromStr,
+{
+    type Err = ParseDelimitedError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Vec::parse_delimited(s, SEP).map(Self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const SEP: char> fmt::Display for Delimited<T, SEP>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.display_joined(SEP).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_joined() {
+        let array = crate::Array::new([1.0, 2.5]).unwrap();
+        assert_eq!(format!("{:.1}", array.display_joined(" | ")), "1.0 | 2.5");
+        assert_eq!(Slice::of(&"a").display_joined(',').to_string(), "a");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_delimited() {
+        assert_eq!(
+            Vec::<u8>::parse_delimited(",1", ','),
+            Err(ParseDelimitedError::EmptyItem { index: 0 })
+        );
+        assert!(matches!(
+            Vec::<u8>::parse_delimited("1;x", ';'),
+            Err(ParseDelimitedError::Item { index: 1, .. })
+        ));
+        assert_eq!(
+            Vec::<u8>::parse_delimited_with("1,,x", ',', EmptyItems::Skip),
+            Err(ParseDelimitedError::Item {
+                index: 2,
+                error: "x".parse::<u8>().unwrap_err()
+            })
+        );
+        assert!(matches!(
+            Vec::<u8>::parse_delimited_with("1,", ',', EmptyItems::Keep),
+            Err(ParseDelimitedError::Item { index: 1, .. })
+        ));
+        let round_trip = "1 2 3".parse::<Delimited<u8, ' '>>().unwrap();
+        assert_eq!(round_trip.to_string(), "1 2 3");
+    }
+}
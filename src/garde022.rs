@@ -0,0 +1,124 @@
+//! Field validation with [`garde`](garde022).
+//!
+//! The minimum length is already enforced by the types,
+//! so `#[garde(length(max = ..))]` is usually all that's needed.
+//! Like `garde`'s implementations for slices, [`Validate`] validates each
+//! element, for `#[garde(dive)]`.
+
+use garde022::{rules::length::HasSimpleLength, Path, Report, Validate};
+
+#[cfg(feature = "alloc")]
+use crate::Vec;
+use crate::{Array, Slice};
+
+impl<T> HasSimpleLength for Slice<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> HasSimpleLength for Array<T, N> {
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> HasSimpleLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Validate for Slice<T>
+where
+    T: Validate,
+{
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        self.as_slice().validate_into(ctx, parent, report)
+    }
+}
+
+impl<T, const N: usize> Validate for Array<T, N>
+where
+    T: Validate,
+{
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        self.as_slice().validate_into(ctx, parent, report)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+{
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        self.as_slice().validate_into(ctx, parent, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garde022::Validate as _;
+
+    use crate::Vec;
+
+    #[derive(garde022::Validate)]
+    struct Host {
+        #[garde(length(min = 1))]
+        name: std::string::String,
+    }
+
+    #[derive(garde022::Validate)]
+    struct Config {
+        #[garde(length(max = 2), dive)]
+        hosts: Vec<Host>,
+    }
+
+    fn host(name: &str) -> Host {
+        Host { name: name.into() }
+    }
+
+    fn invalid_paths(config: &Config) -> std::vec::Vec<std::string::String> {
+        let report = config.validate().unwrap_err();
+        report.iter().map(|(path, _)| path.to_string()).collect()
+    }
+
+    #[test]
+    fn derive() {
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("b")]),
+        };
+        assert!(config.validate().is_ok());
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("")]),
+        };
+        assert_eq!(invalid_paths(&config), ["hosts[1].name"]);
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("b"), host("c")]),
+        };
+        assert_eq!(invalid_paths(&config), ["hosts"]);
+    }
+}
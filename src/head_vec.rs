@@ -0,0 +1,494 @@
+use core::{
+    cmp::Ordering,
+    fmt, iter,
+    num::NonZeroUsize,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use alloc::boxed::Box;
+
+use crate::{Array, NonEmpty, Slice, Vec};
+
+/// A non-empty vector which stores its first element inline.
+///
+/// Unlike [`Vec`], a [`HeadVec`] of a single element does not allocate.
+/// The remaining elements are stored in a lazily allocated buffer.
+///
+/// Since the elements are not contiguous, this does not [`Deref`](core::ops::Deref)
+/// to a [`Slice`] - use [`Self::iter_ne`] or convert to a [`Vec`] instead.
+/// ```
+/// # use nunny::HeadVec;
+/// let mut v = HeadVec::of("hello"); // no allocation
+/// v.push("world");
+/// assert_eq!(*v.first(), "hello");
+/// assert_eq!(*v.last(), "world");
+/// assert_eq!(v, ["hello", "world"]);
+/// ```
+#[derive(Clone, Hash)]
+pub struct HeadVec<T> {
+    head: T,
+    tail: alloc::vec::Vec<T>,
+}
+
+impl<T> Eq for HeadVec<T> where T: Eq {}
+
+/// [`HeadVec`] methods
+impl<T> HeadVec<T> {
+    ///////////
+    // Creation
+    ///////////
+
+    /// Create a new [`HeadVec`], returning the original allocation if it was
+    /// empty.
+    pub fn new(src: alloc::vec::Vec<T>) -> Result<Self, alloc::vec::Vec<T>> {
+        match Vec::new(src) {
+            Ok(it) => Ok(it.into()),
+            Err(src) => Err(src),
+        }
+    }
+    /// Split off the first element, reusing the vec's allocation for the
+    /// rest of the elements.
+    fn from_vec_ne(src: Vec<T>) -> Self {
+        let mut tail = src.into_vec();
+        let head = tail.remove(0);
+        Self { head, tail }
+    }
+
+    ////////////
+    // Utilities
+    ////////////
+
+    /// Create a [`HeadVec`] of a single element, without allocating.
+    pub const fn of(item: T) -> Self {
+        Self {
+            head: item,
+            tail: alloc::vec::Vec::new(),
+        }
+    }
+    /// Creating a [`HeadVec`] where the first element is known.
+    pub fn of_extending(first: T, rest: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            head: first,
+            tail: rest.into_iter().collect(),
+        }
+    }
+
+    //////////////////
+    // Shimmed methods
+    //////////////////
+
+    /// Returns the known non-zero length.
+    pub fn len_ne(&self) -> NonZeroUsize {
+        // Safety:
+        // - the head is always present
+        unsafe { crate::non_zero_usize(self.tail.len() + 1) }
+    }
+    /// Returns the known non-zero length.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len_ne().get()
+    }
+    /// Returns the first element, guaranteed.
+    pub fn first(&self) -> &T {
+        &self.head
+    }
+    /// Returns the first element, guaranteed.
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.head
+    }
+    /// Returns the first element, guaranteed, and the rest of the elements.
+    pub fn split_first(&self) -> (&T, &[T]) {
+        (&self.head, &self.tail)
+    }
+    /// Returns the first element, guaranteed, and the rest of the elements.
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        (&mut self.head, &mut self.tail)
+    }
+    /// Returns the last element, guaranteed.
+    pub fn last(&self) -> &T {
+        self.tail.last().unwrap_or(&self.head)
+    }
+    /// Returns the last element, guaranteed.
+    pub fn last_mut(&mut self) -> &mut T {
+        self.tail.last_mut().unwrap_or(&mut self.head)
+    }
+    /// Returns a reference to an element, or [`None`] if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index.checked_sub(1) {
+            None => Some(&self.head),
+            Some(index) => self.tail.get(index),
+        }
+    }
+    /// Returns a mutable reference to an element, or [`None`] if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index.checked_sub(1) {
+            None => Some(&mut self.head),
+            Some(index) => self.tail.get_mut(index),
+        }
+    }
+
+    /// Appends an element to the back of the collection.
+    ///
+    /// See [`push`](alloc::vec::Vec::push).
+    pub fn push(&mut self, value: T) {
+        self.tail.push(value)
+    }
+    /// Removes the last element and returns it, or [`None`] if that would
+    /// leave the collection empty.
+    ///
+    /// See [`pop`](alloc::vec::Vec::pop).
+    pub fn pop(&mut self) -> Option<T> {
+        self.tail.pop()
+    }
+    /// Shortens the vector to a guaranteed-nonzero length
+    ///
+    /// See [`truncate`](alloc::vec::Vec::truncate).
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.tail.truncate(len.get() - 1)
+    }
+
+    /// Returns a [`NonEmpty`] heap-allocated vec.
+    pub fn into_vec_ne(self) -> Vec<T> {
+        let Self { head, tail } = self;
+        let mut it = Vec::of_with_capacity(head, tail.len() + 1);
+        it.extend(tail);
+        it
+    }
+    /// Return a [`NonEmpty`] boxed slice.
+    pub fn into_boxed_slice(self) -> Box<Slice<T>> {
+        self.into_vec_ne().into_boxed_slice()
+    }
+}
+
+/// Iterators for [`HeadVec`].
+impl<T> HeadVec<T> {
+    pub fn iter(&self) -> iter::Chain<iter::Once<&T>, slice::Iter<'_, T>> {
+        iter::once(&self.head).chain(&self.tail)
+    }
+    pub fn iter_mut(&mut self) -> iter::Chain<iter::Once<&mut T>, slice::IterMut<'_, T>> {
+        iter::once(&mut self.head).chain(&mut self.tail)
+    }
+    pub fn iter_ne(&self) -> NonEmpty<iter::Chain<iter::Once<&T>, slice::Iter<'_, T>>> {
+        NonEmpty { inner: self.iter() }
+    }
+    pub fn iter_mut_ne(
+        &mut self,
+    ) -> NonEmpty<iter::Chain<iter::Once<&mut T>, slice::IterMut<'_, T>>> {
+        NonEmpty {
+            inner: self.iter_mut(),
+        }
+    }
+    pub fn into_iter_ne(self) -> NonEmpty<iter::Chain<iter::Once<T>, alloc::vec::IntoIter<T>>> {
+        NonEmpty {
+            inner: self.into_iter(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for HeadVec<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T> Index<usize> for HeadVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index.checked_sub(1) {
+            None => &self.head,
+            Some(index) => &self.tail[index],
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for HeadVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index.checked_sub(1) {
+            None => &mut self.head,
+            Some(index) => &mut self.tail[index],
+        }
+    }
+}
+
+mod iter_impls {
+    use super::*;
+
+    impl<T> IntoIterator for HeadVec<T> {
+        type Item = T;
+
+        type IntoIter = iter::Chain<iter::Once<T>, alloc::vec::IntoIter<T>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            iter::once(self.head).chain(self.tail)
+        }
+    }
+    impl<'a, T> IntoIterator for &'a HeadVec<T> {
+        type Item = &'a T;
+
+        type IntoIter = iter::Chain<iter::Once<&'a T>, slice::Iter<'a, T>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+    impl<'a, T> IntoIterator for &'a mut HeadVec<T> {
+        type Item = &'a mut T;
+
+        type IntoIter = iter::Chain<iter::Once<&'a mut T>, slice::IterMut<'a, T>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+    impl<'a, T> Extend<&'a T> for HeadVec<T>
+    where
+        T: Copy + 'a,
+    {
+        fn extend<II: IntoIterator<Item = &'a T>>(&mut self, iter: II) {
+            self.tail.extend(iter)
+        }
+    }
+    impl<T> Extend<T> for HeadVec<T> {
+        fn extend<II: IntoIterator<Item = T>>(&mut self, iter: II) {
+            self.tail.extend(iter)
+        }
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    fn eq<'a, T, U>(left: &HeadVec<T>, right: impl ExactSizeIterator<Item = &'a U>) -> bool
+    where
+        T: PartialEq<U>,
+        U: 'a,
+    {
+        left.len() == right.len() && left.iter().zip(right).all(|(l, r)| l == r)
+    }
+
+    impl<T, U> PartialEq<HeadVec<U>> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &HeadVec<U>) -> bool {
+            self.tail.len() == other.tail.len()
+                && self.head == other.head
+                && self.tail.iter().zip(&other.tail).all(|(l, r)| l == r)
+        }
+    }
+    impl<T, U> PartialEq<[U]> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            eq(self, other.iter())
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<[U; N]> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U; N]) -> bool {
+            eq(self, other.iter())
+        }
+    }
+    impl<T, U> PartialEq<Slice<U>> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Slice<U>) -> bool {
+            eq(self, other.iter())
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<Array<U, N>> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Array<U, N>) -> bool {
+            eq(self, other.iter())
+        }
+    }
+    impl<T, U> PartialEq<Vec<U>> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Vec<U>) -> bool {
+            eq(self, other.iter())
+        }
+    }
+    impl<T, U> PartialEq<alloc::vec::Vec<U>> for HeadVec<T>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+            eq(self, other.iter())
+        }
+    }
+}
+
+mod cmp {
+    use super::*;
+
+    impl<T> PartialOrd for HeadVec<T>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.iter().partial_cmp(other.iter())
+        }
+    }
+    impl<T> Ord for HeadVec<T>
+    where
+        T: Ord,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.iter().cmp(other.iter())
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<T> From<Vec<T>> for HeadVec<T> {
+        fn from(value: Vec<T>) -> Self {
+            Self::from_vec_ne(value)
+        }
+    }
+    impl<T> From<Box<Slice<T>>> for HeadVec<T> {
+        fn from(value: Box<Slice<T>>) -> Self {
+            Vec::from(value).into()
+        }
+    }
+    impl<T, const N: usize> From<Array<T, N>> for HeadVec<T> {
+        fn from(value: Array<T, N>) -> Self {
+            let mut it = value.into_iter();
+            Self {
+                head: match it.next() {
+                    Some(it) => it,
+                    // Safety:
+                    // - Array is non-empty by construction
+                    None => unsafe { crate::unreachable() },
+                },
+                tail: it.collect(),
+            }
+        }
+    }
+    impl<T> TryFrom<alloc::vec::Vec<T>> for HeadVec<T> {
+        type Error = alloc::vec::Vec<T>;
+
+        fn try_from(value: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+            HeadVec::new(value)
+        }
+    }
+
+    impl<T> From<HeadVec<T>> for Vec<T> {
+        fn from(value: HeadVec<T>) -> Self {
+            value.into_vec_ne()
+        }
+    }
+    impl<T> From<HeadVec<T>> for Box<Slice<T>> {
+        fn from(value: HeadVec<T>) -> Self {
+            value.into_boxed_slice()
+        }
+    }
+    impl<T> From<HeadVec<T>> for alloc::vec::Vec<T> {
+        fn from(value: HeadVec<T>) -> Self {
+            value.into_vec_ne().into_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_keeps_head() {
+        let mut v = HeadVec::of_extending(1, [2, 3]);
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), None);
+        assert_eq!(v, [1]);
+
+        let mut v = HeadVec::of_extending(1, [2, 3]);
+        v.truncate(NonZeroUsize::MIN);
+        assert_eq!(v, [1]);
+        assert_eq!(*v.last(), 1);
+    }
+
+    #[test]
+    fn index() {
+        let mut v = HeadVec::of_extending(1, [2, 3]);
+        assert_eq!((v[0], v[1], v[2]), (1, 2, 3));
+        assert_eq!((v.get(0), v.get(1), v.get(3)), (Some(&1), Some(&2), None));
+        v[0] = 10;
+        *v.get_mut(1).unwrap() = 20;
+        assert_eq!(v, [10, 20, 3]);
+        assert_eq!(v.split_first(), (&10, &[20, 3][..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _ = HeadVec::of(1)[1];
+    }
+
+    #[test]
+    fn partial_eq() {
+        let v = HeadVec::of_extending(1, [2]);
+        assert_eq!(v, HeadVec::of_extending(1, [2]));
+        assert_ne!(v, HeadVec::of_extending(1, [3]));
+        assert_ne!(v, HeadVec::of(1));
+        assert_eq!(v, [1, 2]);
+        assert_ne!(v, [1, 2, 3]);
+        assert_eq!(v, *Slice::new(&[1, 2]).unwrap());
+        assert_eq!(v, Array::new([1, 2]).unwrap());
+        assert_eq!(v, Vec::new(alloc::vec![1, 2]).unwrap());
+        assert_eq!(v, alloc::vec![1, 2]);
+        assert!(v < HeadVec::of_extending(1, [3]));
+    }
+
+    #[test]
+    fn convert() {
+        let src = alloc::vec![1, 2, 3];
+        let ptr = src.as_ptr();
+        let v = HeadVec::new(src).unwrap();
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(v.tail.as_ptr(), ptr, "the tail reuses the allocation");
+        assert_eq!(HeadVec::<i32>::try_from(alloc::vec![]), Err(alloc::vec![]));
+
+        assert_eq!(HeadVec::from(Vec::new(alloc::vec![1, 2]).unwrap()), [1, 2]);
+        assert_eq!(HeadVec::from(Array::new([1, 2]).unwrap()), [1, 2]);
+        assert_eq!(HeadVec::from(Vec::of(1).into_boxed_slice()), [1]);
+
+        let v = HeadVec::of_extending(1, [2]);
+        assert_eq!(Vec::from(v.clone()), [1, 2]);
+        assert_eq!(*Box::<Slice<_>>::from(v.clone()), [1, 2]);
+        assert_eq!(alloc::vec::Vec::from(v), [1, 2]);
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn serde() {
+        let v = HeadVec::of_extending(1, [2]);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2]");
+        assert_eq!(serde_json::from_str::<HeadVec<i32>>("[1,2]").unwrap(), v);
+        assert_eq!(serde_json::from_str::<HeadVec<i32>>("[1]").unwrap(), [1]);
+        assert!(serde_json::from_str::<HeadVec<i32>>("[]").is_err());
+    }
+
+    #[cfg(feature = "schemars1")]
+    #[test]
+    fn schema() {
+        assert_eq!(
+            schemars1::schema_for!(HeadVec<i32>),
+            schemars1::schema_for!(Vec<i32>)
+        );
+    }
+}
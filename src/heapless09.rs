@@ -0,0 +1,372 @@
+//! Non-empty bounded vectors, using [`heapless`](heapless09) on targets
+//! without an allocator.
+
+use core::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+};
+
+use heapless09::{vec::IntoIter, Vec};
+
+use crate::{array_vec::AssertCap, Array, Error, NonEmpty, Slice};
+
+impl<T, const N: usize> Eq for NonEmpty<Vec<T, N>> where T: Eq {}
+
+/// [`heapless::Vec`](heapless09::Vec) methods
+impl<T, const N: usize> NonEmpty<Vec<T, N>> {
+    ///////////
+    // Creation
+    ///////////
+
+    /// Create a new [`NonEmpty`] bounded vec, returning the original vec if it
+    /// was empty.
+    pub fn new(src: Vec<T, N>) -> Result<Self, Vec<T, N>> {
+        match src.is_empty() {
+            false => Ok(unsafe { Self::new_unchecked(src) }),
+            true => Err(src),
+        }
+    }
+    crate::map_non_empty! {
+        /// Create a new [`NonEmpty`] bounded vec
+        new_ref(&Vec<T, N>) -> &Self: Self::new_ref_unchecked;
+        /// Create a new [`NonEmpty`] bounded vec
+        new_mut(&mut Vec<T, N>) -> &mut Self: Self::new_mut_unchecked;
+    }
+    /// Create a new [`NonEmpty`] bounded vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_unchecked(src: Vec<T, N>) -> Self {
+        debug_assert!(!src.is_empty());
+        Self { inner: src }
+    }
+    /// Create a new [`NonEmpty`] bounded vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_ref_unchecked(src: &Vec<T, N>) -> &Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &*(src as *const Vec<T, N> as *const Self) }
+    }
+    /// Create a new [`NonEmpty`] bounded vec
+    ///
+    /// # Safety
+    /// - `src` must not be empty
+    pub unsafe fn new_mut_unchecked(src: &mut Vec<T, N>) -> &mut Self {
+        debug_assert!(!src.is_empty());
+        // Safety
+        // - #[repr(transparent)]
+        unsafe { &mut *(src as *mut Vec<T, N> as *mut Self) }
+    }
+
+    ////////////
+    // Utilities
+    ////////////
+
+    /// Create a [`NonEmpty`] bounded vec, of a single element.
+    ///
+    /// `N` must not be zero.
+    /// ```compile_fail
+    /// # use nunny::NonEmpty;
+    /// let v = NonEmpty::<heapless09::Vec<_, 0>>::of("oops"); // no capacity!
+    /// ```
+    pub fn of(item: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCap::<N, 1>::NONZERO;
+        Self::from_array(Array::of(item))
+    }
+    /// Create a [`NonEmpty`] bounded vec from a [`NonEmpty`] array, which must
+    /// fit in the capacity.
+    /// ```compile_fail
+    /// # use nunny::{array, NonEmpty};
+    /// let v = NonEmpty::<heapless09::Vec<_, 1>>::from_array(array![1, 2]); // too big!
+    /// ```
+    pub fn from_array<const M: usize>(src: Array<T, M>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCap::<N, M>::FITS;
+        // Safety:
+        // - src is non-empty by construction
+        unsafe { Self::new_unchecked(Vec::from_array(src.into_array())) }
+    }
+    fn check(&self) {
+        debug_assert_ne!(self.inner.len(), 0)
+    }
+
+    /// Returns a [`heapless::Vec`](heapless09::Vec).
+    pub fn as_vec(&self) -> &Vec<T, N> {
+        self.check();
+        &self.inner
+    }
+    /// Returns a [`heapless::Vec`](heapless09::Vec).
+    ///
+    /// # Safety
+    /// - returned vec must not be emptied through this reference
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<T, N> {
+        self.check();
+        &mut self.inner
+    }
+    /// Returns a [`heapless::Vec`](heapless09::Vec).
+    pub fn into_vec(self) -> Vec<T, N> {
+        let Self { inner } = self;
+        inner
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_slice_ne(&self) -> &Slice<T> {
+        unsafe { Slice::new_unchecked(self.as_vec()) }
+    }
+    /// Returns a [`NonEmpty`] slice.
+    pub fn as_mut_slice_ne(&mut self) -> &mut Slice<T> {
+        unsafe { Slice::new_mut_unchecked(self.as_mut_vec()) }
+    }
+
+    //////////////////
+    // Shimmed methods
+    //////////////////
+
+    /// Returns the known non-zero capacity.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.as_vec().is_full()
+    }
+
+    /// Appends an element to the back of the collection, returning it if
+    /// there is no spare capacity.
+    ///
+    /// See [`push`](heapless09::Vec::push).
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        // Safety:
+        // - operation does not remove elements
+        unsafe { self.as_mut_vec() }.push(item)
+    }
+    /// Removes the last element and returns it, or [`None`] if that would
+    /// leave the collection empty.
+    ///
+    /// See [`pop`](heapless09::Vec::pop).
+    pub fn pop(&mut self) -> Option<T> {
+        match self.len_ne().get() {
+            1 => None,
+            // Safety:
+            // - at least one element remains
+            _ => unsafe { self.as_mut_vec() }.pop(),
+        }
+    }
+    /// Inserts an element at position `index`, returning it if there is no
+    /// spare capacity.
+    ///
+    /// See [`insert`](heapless09::Vec::insert).
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        // Safety:
+        // - operation does not remove elements
+        unsafe { self.as_mut_vec() }.insert(index, element)
+    }
+    /// Shortens the vector to a guaranteed-nonzero length
+    ///
+    /// See [`truncate`](heapless09::Vec::truncate).
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        // Safety:
+        // - len is not zero, so vector will not be emptied
+        unsafe { self.as_mut_vec() }.truncate(len.get());
+        self.check();
+    }
+}
+
+/// Known non-empty iterator for [`NonEmpty<heapless::Vec>`](NonEmpty).
+impl<T, const N: usize> NonEmpty<Vec<T, N>> {
+    pub fn into_iter_ne(self) -> NonEmpty<IntoIter<T, N, usize>> {
+        NonEmpty {
+            inner: self.into_vec().into_iter(),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for NonEmpty<Vec<T, N>> {
+    type Target = Slice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_ne()
+    }
+}
+
+impl<T, const N: usize> DerefMut for NonEmpty<Vec<T, N>> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_ne()
+    }
+}
+
+crate::as_ref_as_mut! {
+    <T, const N: usize> for NonEmpty<Vec<T, N>> as [T];
+    <T, const N: usize> for NonEmpty<Vec<T, N>> as Slice<T>;
+}
+
+crate::borrow_borrow_mut! {
+    <T, const N: usize> for NonEmpty<Vec<T, N>> as [T];
+    <T, const N: usize> for NonEmpty<Vec<T, N>> as Slice<T>;
+}
+
+crate::slice_iter! {
+    <T, const N: usize> for NonEmpty<Vec<T, N>>
+}
+
+impl<T, const N: usize> IntoIterator for NonEmpty<Vec<T, N>> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<T, U, const N: usize, const M: usize> PartialEq<NonEmpty<Vec<U, M>>> for NonEmpty<Vec<T, N>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<U, M>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<[U]> for NonEmpty<Vec<T, N>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for NonEmpty<Vec<T, N>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &[U; M]) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<Slice<U>> for NonEmpty<Vec<T, N>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Slice<U>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize, const M: usize> PartialEq<Array<U, M>> for NonEmpty<Vec<T, N>>
+    where
+        T: PartialEq<U>,
+    {
+        fn eq(&self, other: &Array<U, M>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<T, U, const N: usize> PartialEq<NonEmpty<Vec<T, N>>> for [U]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, N>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize, const M: usize> PartialEq<NonEmpty<Vec<T, N>>> for [U; M]
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, N>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<T, U, const N: usize> PartialEq<NonEmpty<Vec<T, N>>> for Slice<U>
+    where
+        U: PartialEq<T>,
+    {
+        fn eq(&self, other: &NonEmpty<Vec<T, N>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod cmp {
+    use super::*;
+
+    impl<T, const N: usize> PartialOrd for NonEmpty<Vec<T, N>>
+    where
+        T: PartialOrd,
+    {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            <[_] as PartialOrd>::partial_cmp(self, other)
+        }
+    }
+    impl<T, const N: usize> Ord for NonEmpty<Vec<T, N>>
+    where
+        T: Ord,
+    {
+        fn cmp(&self, other: &Self) -> Ordering {
+            <[_] as Ord>::cmp(self, other)
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<T, const N: usize, const M: usize> From<Array<T, M>> for NonEmpty<Vec<T, N>> {
+        fn from(value: Array<T, M>) -> Self {
+            Self::from_array(value)
+        }
+    }
+    impl<T, const N: usize> TryFrom<Vec<T, N>> for NonEmpty<Vec<T, N>> {
+        type Error = Vec<T, N>;
+
+        fn try_from(value: Vec<T, N>) -> Result<Self, Self::Error> {
+            Self::new(value)
+        }
+    }
+    impl<'a, T, const N: usize> TryFrom<&'a Vec<T, N>> for &'a NonEmpty<Vec<T, N>> {
+        type Error = Error;
+
+        fn try_from(value: &'a Vec<T, N>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<T, N>>::new_ref(value).ok_or(Error(()))
+        }
+    }
+    impl<'a, T, const N: usize> TryFrom<&'a mut Vec<T, N>> for &'a mut NonEmpty<Vec<T, N>> {
+        type Error = Error;
+
+        fn try_from(value: &'a mut Vec<T, N>) -> Result<Self, Self::Error> {
+            NonEmpty::<Vec<T, N>>::new_mut(value).ok_or(Error(()))
+        }
+    }
+    impl<T, const N: usize> From<NonEmpty<Vec<T, N>>> for Vec<T, N> {
+        fn from(value: NonEmpty<Vec<T, N>>) -> Self {
+            value.into_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut v = NonEmpty::<Vec<_, 2>>::of(1);
+        assert_eq!(v.push(2), Ok(()));
+        assert_eq!(v.push(3), Err(3));
+        assert!(v.is_full());
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), None);
+        assert_eq!(v, [1]);
+        assert_eq!(v.iter_ne().last(), &1);
+    }
+}
@@ -320,6 +320,17 @@ where
     pub fn relax(self) -> I {
         self.inner
     }
+    /// Collect this iterator into any collection which implements
+    /// [`FromNonEmptyIterator`], preserving the invariant.
+    ///
+    /// [`NonEmpty<Vec>`] implements [`FromNonEmptyIterator`], as do the
+    /// vectors from optional features like `smallvec`.
+    pub fn collect<B>(self) -> B
+    where
+        B: FromNonEmptyIterator<I::Item>,
+    {
+        B::from_iter_ne(self)
+    }
     /// Collect this iterator into a [`NonEmpty<Vec>`].
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -330,6 +341,32 @@ where
         //   (so it always has one element)
         unsafe { crate::Vec::new_unchecked(self.inner.collect()) }
     }
+    /// Fallible version of [`Self::collect_vec`], returning an error if the
+    /// allocator reports a failure.
+    ///
+    /// Items are pushed one at a time with [`try_push`](crate::Vec::try_push),
+    /// so growing the buffer (which may reallocate and move it) reports
+    /// failure instead of aborting the process.
+    /// ```
+    /// # use nunny::slice;
+    /// let v = slice![1, 2, 3].iter_ne().map(|it| it * 2).try_collect_vec_in_place().unwrap();
+    /// assert_eq!(v, [2, 4, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn try_collect_vec_in_place(
+        self,
+    ) -> Result<crate::Vec<I::Item>, alloc::collections::TryReserveError> {
+        let mut inner = self.inner;
+        let mut it = crate::Vec::try_of_with_capacity(
+            unwrap!(inner.next()),
+            inner.size_hint().0.saturating_add(1),
+        )?;
+        for item in inner {
+            it.try_push(item)?;
+        }
+        Ok(it)
+    }
     /// Collect [`Ok`] items into a [`NonEmpty<Vec>`], short-circuiting on [`Err`].
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -365,3 +402,25 @@ where
         unwrap!(self.inner.peek_mut())
     }
 }
+
+/// Conversion from a [`NonEmpty`] iterator, preserving the invariant.
+///
+/// This is the [`NonEmpty`] version of [`FromIterator`],
+/// used by [`NonEmpty::collect`].
+pub trait FromNonEmptyIterator<T>: Sized {
+    /// Creates a value from a [`NonEmpty`] iterator.
+    fn from_iter_ne<I>(iter: NonEmpty<I>) -> Self
+    where
+        I: Iterator<Item = T>;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> FromNonEmptyIterator<T> for crate::Vec<T> {
+    fn from_iter_ne<I>(iter: NonEmpty<I>) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.collect_vec()
+    }
+}
@@ -39,10 +39,20 @@
 //!   ```
 //!
 //! Extensive feature gating supporting:
-//! - `no-std` environments with no allocator.
+//! - `no-std` environments with no allocator,
+//!   including bounded vectors via [`heapless`](::heapless09).
 //! - `alloc`-enabled environments.
 //! - full-`std`-enabled environments.
-//! - interaction with crates like [`serde`](::serde1) and [`arbitrary`](::arbitrary1).
+//! - interaction with crates like [`serde`](::serde1), [`borsh`](::borsh1),
+//!   [`bincode`](::bincode2) and [`arbitrary`](::arbitrary1).
+//! - custom allocators, via [`allocator-api2`](::allocator_api2_02) on stable,
+//!   and arenas, via [`bumpalo`](::bumpalo3).
+//! - inline vectors, via [`smallvec`](::smallvec1), [`arrayvec`](::arrayvec07)
+//!   and [`tinyvec`](::tinyvec1).
+//! - parallel iteration, via [`rayon`](::rayon1).
+//! - argument parsing, via [`clap`](::clap4), from a single delimited value.
+//! - casting, via [`bytemuck`](::bytemuck1), for [`Array`]s of
+//!   [the lengths `bytemuck` supports](::bytemuck1::Pod#foreign-impls).
 //!
 //! Iterator support:
 //!   Specialized [`Iterator`] methods remove branches to handle empty iterators,
@@ -76,15 +86,58 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(all(feature = "allocator-api2-02", not(nunny_allocator_api2_nightly)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "allocator-api2")))]
+mod allocator_api2_02;
 #[cfg(feature = "arbitrary1")]
 #[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
 mod arbitrary1;
+#[cfg(feature = "arrayvec07")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+mod arrayvec07;
+#[cfg(feature = "bincode2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+mod bincode2;
+#[cfg(feature = "bolero013")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bolero")))]
+mod bolero013;
+#[cfg(feature = "borsh1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+mod borsh1;
+#[cfg(feature = "bumpalo3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+mod bumpalo3;
+#[cfg(feature = "bytemuck1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+mod bytemuck1;
+#[cfg(feature = "clap4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+mod clap4;
+#[cfg(feature = "garde022")]
+#[cfg_attr(docsrs, doc(cfg(feature = "garde")))]
+mod garde022;
+// `#[derive(garde::Validate)]` refers to `::garde`
+#[cfg(test)]
+#[cfg(feature = "garde022")]
+extern crate garde022 as garde;
+#[cfg(feature = "heapless09")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+mod heapless09;
 #[cfg(feature = "proptest1")]
 #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
 mod proptest1;
 #[cfg(feature = "quickcheck1")]
 #[cfg_attr(docsrs, doc(cfg(feature = "quickcheck")))]
 mod quickcheck1;
+#[cfg(feature = "rand09")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod rand09;
+#[cfg(feature = "rayon1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+mod rayon1;
+#[cfg(feature = "rkyv08")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+mod rkyv08;
 #[cfg(feature = "schemars08")]
 #[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
 mod schemars08;
@@ -97,8 +150,32 @@ mod schemars1;
 #[cfg(feature = "serde1")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serde1;
+#[cfg(feature = "smallvec1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+mod smallvec1;
+#[cfg(feature = "tinyvec1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+mod tinyvec1;
+#[cfg(feature = "utoipa5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utoipa")))]
+mod utoipa5;
+#[cfg(feature = "validator020")]
+#[cfg_attr(docsrs, doc(cfg(feature = "validator")))]
+mod validator020;
+// `#[derive(validator::Validate)]` refers to `::validator`
+#[cfg(test)]
+#[cfg(feature = "validator020")]
+extern crate validator020 as validator;
+#[cfg(feature = "zerocopy08")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+mod zerocopy08;
 
 mod array;
+mod array_vec;
+mod delimited;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod head_vec;
 mod iter;
 mod mirror_std {
     mod cmp;
@@ -107,12 +184,72 @@ mod mirror_std {
     mod try_from;
 }
 mod slice;
+/// Helpers for (de)serializing with [`serde`](::serde1).
+#[cfg(all(feature = "serde1", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "alloc"))))]
+pub mod serde {
+    pub use crate::serde1::{one_or_many, Append, InPlace, OneOrMany};
+}
+/// Argument parsing with [`clap`](::clap4).
+///
+/// **A [`Vec`] argument takes a single delimited value**,
+/// e.g `--hosts a,b,c`, and is required.
+/// This differs from `#[arg(num_args = 1..)] hosts: Vec<String>`:
+/// `--hosts a b` and `--hosts a --hosts b` are errors,
+/// and a positional argument doesn't collect `app a b c`.
+/// `clap` parses each value on its own, and its derive only collects multiple
+/// values into a field spelled `Vec<T>`, which must be a [`std::vec::Vec`].
+///
+/// To accept multiple values, keep a [`std::vec::Vec`] field with
+/// `#[arg(num_args = 1.., required = true)]`,
+/// and convert it with [`Vec::new`].
+#[cfg(feature = "clap4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap {
+    pub use crate::clap4::VecValueParser;
+}
+/// Generators for [`bolero`](::bolero013).
+#[cfg(feature = "bolero013")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bolero")))]
+pub mod bolero {
+    pub use crate::bolero013::{vec, VecGenerator};
+}
+/// Strategies for [`proptest`](::proptest1).
+#[cfg(feature = "proptest1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest {
+    pub use crate::proptest1::{
+        array, slice, vec, ArrayStrategy, SliceStrategy, VecStrategy, VecValueTree,
+    };
+}
+/// Parallel iteration with [`rayon`](::rayon1).
+#[cfg(feature = "rayon1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub mod rayon {
+    pub use crate::rayon1::NonEmptyParallelIterator;
+}
+/// Archived types for [`rkyv`](::rkyv08).
+#[cfg(feature = "rkyv08")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub mod rkyv {
+    pub use crate::rkyv08::ArchivedVec;
+}
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod vec;
 
 use core::{convert::Infallible, fmt, num::NonZeroUsize};
 
+pub use array_vec::{ArrayVec, ArrayVecIntoIter};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use delimited::Delimited;
+pub use delimited::{DisplayJoined, EmptyItems, ParseDelimitedError};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use head_vec::HeadVec;
+pub use iter::FromNonEmptyIterator;
+
 /// A wrapper struct around non-empty slices/arrays/vectors.
 ///
 /// You may wish to use the following type aliases instead:
@@ -122,6 +259,15 @@ use core::{convert::Infallible, fmt, num::NonZeroUsize};
 ///
 /// See also [crate documentation](crate)
 #[derive(Debug, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "zerocopy08",
+    derive(
+        ::zerocopy08::KnownLayout,
+        ::zerocopy08::Immutable,
+        ::zerocopy08::IntoBytes
+    ),
+    zerocopy(crate = "::zerocopy08")
+)]
 #[repr(transparent)]
 pub struct NonEmpty<T: ?Sized> {
     inner: T,
@@ -251,9 +397,14 @@ pub mod __private {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Error(());
 
+impl Error {
+    /// Shared with the integrations which reject empty input.
+    pub(crate) const MESSAGE: &'static str = "collection was empty";
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("collection was empty")
+        f.write_str(Self::MESSAGE)
     }
 }
 
@@ -337,6 +488,18 @@ macro_rules! as_ref_as_mut {
 
         )*
     };
+    ($(<$ty_param:ident $(: $ty_bound:path)? $(, $param:ident: $bound:path)?> for $self:ty as $ty:ty);* $(;)?) => {
+        $(
+            impl<$ty_param $(: $ty_bound)? $(, $param: $bound)?> ::core::convert::AsRef<$ty> for $self {
+                fn as_ref(&self) -> &$ty { self }
+            }
+
+            impl<$ty_param $(: $ty_bound)? $(, $param: $bound)?> ::core::convert::AsMut<$ty> for $self {
+                fn as_mut(&mut self) -> &mut $ty { self }
+            }
+
+        )*
+    };
 }
 pub(crate) use as_ref_as_mut;
 
@@ -353,9 +516,53 @@ macro_rules! borrow_borrow_mut {
 
         )*
     };
+    ($(<$ty_param:ident $(: $ty_bound:path)? $(, $param:ident: $bound:path)?> for $self:ty as $ty:ty);* $(;)?) => {
+        $(
+            impl<$ty_param $(: $ty_bound)? $(, $param: $bound)?> ::core::borrow::Borrow<$ty> for $self {
+                fn borrow(&self) -> &$ty { self }
+            }
+
+            impl<$ty_param $(: $ty_bound)? $(, $param: $bound)?> ::core::borrow::BorrowMut<$ty> for $self {
+                fn borrow_mut(&mut self) -> &mut $ty { self }
+            }
+
+        )*
+    };
 }
 pub(crate) use borrow_borrow_mut;
 
+/// Forward `&mut self` methods which cannot empty a vec to the inner vec,
+/// linking to their documentation on `$path`.
+#[cfg(any(
+    feature = "alloc",
+    feature = "arrayvec07",
+    feature = "bumpalo3",
+    feature = "smallvec1",
+    feature = "tinyvec1"
+))]
+macro_rules! forward_mut {
+    ($path:literal; $( $(#[$meta:meta])* $vis:vis fn $ident:ident(&mut self $(,$arg:ident: $ty:ty)* $(,)?) $(-> $ret:ty)?);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            ///
+            #[doc = concat!("See [`", stringify!($ident), "`](", $path, "::", stringify!($ident), ").")]
+            $vis fn $ident(&mut self $(, $arg: $ty)*) $(-> $ret)? {
+                // Safety:
+                // - operation does not remove elements
+                unsafe { self.as_mut_vec() }.$ident($($arg),*)
+            }
+        )*
+    };
+}
+#[cfg(any(
+    feature = "alloc",
+    feature = "arrayvec07",
+    feature = "bumpalo3",
+    feature = "smallvec1",
+    feature = "tinyvec1"
+))]
+pub(crate) use forward_mut;
+
 macro_rules! slice_iter {
     (<$ty_param:ident $(, const $const_param:ident: usize)?> for $self:ty) => {
         impl<'a, $ty_param $(, const $const_param: usize)?> ::core::iter::IntoIterator for &'a $self {
@@ -377,6 +584,343 @@ macro_rules! slice_iter {
 }
 pub(crate) use slice_iter;
 
+/// Shared API for [`NonEmpty`] wrappers of third-party vecs,
+/// which deref to a `[$item]` and may be truncated and popped.
+#[cfg(any(feature = "arrayvec07", feature = "smallvec1", feature = "tinyvec1"))]
+macro_rules! vec_like {
+    (
+        [$($generics:tt)*] $vec:ty {
+            Item = $item:ty;
+            IntoIter = $into_iter:ty;
+            name = $name:literal;
+            path = $path:literal;
+        }
+    ) => {
+        impl<$($generics)*> Eq for $crate::NonEmpty<$vec> where $item: Eq {}
+
+        impl<$($generics)*> $crate::NonEmpty<$vec> {
+            #[doc = concat!("Create a new [`NonEmpty`] ", $name, ", returning the original vec if it")]
+            /// was empty.
+            pub fn new(src: $vec) -> Result<Self, $vec> {
+                match src.is_empty() {
+                    false => Ok(unsafe { Self::new_unchecked(src) }),
+                    true => Err(src),
+                }
+            }
+            $crate::map_non_empty! {
+                #[doc = concat!("Create a new [`NonEmpty`] ", $name)]
+                new_ref(&$vec) -> &Self: Self::new_ref_unchecked;
+                #[doc = concat!("Create a new [`NonEmpty`] ", $name)]
+                new_mut(&mut $vec) -> &mut Self: Self::new_mut_unchecked;
+            }
+            #[doc = concat!("Create a new [`NonEmpty`] ", $name)]
+            ///
+            /// # Safety
+            /// - `src` must not be empty
+            pub unsafe fn new_unchecked(src: $vec) -> Self {
+                debug_assert!(!src.is_empty());
+                Self { inner: src }
+            }
+            #[doc = concat!("Create a new [`NonEmpty`] ", $name)]
+            ///
+            /// # Safety
+            /// - `src` must not be empty
+            pub unsafe fn new_ref_unchecked(src: &$vec) -> &Self {
+                debug_assert!(!src.is_empty());
+                // Safety
+                // - #[repr(transparent)]
+                unsafe { &*(src as *const $vec as *const Self) }
+            }
+            #[doc = concat!("Create a new [`NonEmpty`] ", $name)]
+            ///
+            /// # Safety
+            /// - `src` must not be empty
+            pub unsafe fn new_mut_unchecked(src: &mut $vec) -> &mut Self {
+                debug_assert!(!src.is_empty());
+                // Safety
+                // - #[repr(transparent)]
+                unsafe { &mut *(src as *mut $vec as *mut Self) }
+            }
+            fn check(&self) {
+                debug_assert_ne!(self.inner.len(), 0)
+            }
+
+            #[doc = concat!("Returns the inner [`", $path, "`].")]
+            pub fn as_vec(&self) -> &$vec {
+                self.check();
+                &self.inner
+            }
+            #[doc = concat!("Returns the inner [`", $path, "`].")]
+            ///
+            /// # Safety
+            /// - returned vec must not be emptied through this reference
+            pub unsafe fn as_mut_vec(&mut self) -> &mut $vec {
+                self.check();
+                &mut self.inner
+            }
+            #[doc = concat!("Returns the inner [`", $path, "`].")]
+            pub fn into_vec(self) -> $vec {
+                let Self { inner } = self;
+                inner
+            }
+            /// Returns a [`NonEmpty`] slice.
+            pub fn as_slice_ne(&self) -> &$crate::Slice<$item> {
+                unsafe { $crate::Slice::new_unchecked(self.as_vec()) }
+            }
+            /// Returns a [`NonEmpty`] slice.
+            pub fn as_mut_slice_ne(&mut self) -> &mut $crate::Slice<$item> {
+                unsafe { $crate::Slice::new_mut_unchecked(self.as_mut_vec()) }
+            }
+            /// Shortens the vector to a guaranteed-nonzero length
+            ///
+            #[doc = concat!("See [`truncate`](", $path, "::truncate).")]
+            pub fn truncate(&mut self, len: ::core::num::NonZeroUsize) {
+                // Safety:
+                // - len is not zero, so vector will not be emptied
+                unsafe { self.as_mut_vec() }.truncate(len.get());
+                self.check();
+            }
+            /// Removes the last element and returns it, or [`None`] if that would
+            /// leave the collection empty.
+            ///
+            #[doc = concat!("See [`pop`](", $path, "::pop).")]
+            pub fn pop(&mut self) -> Option<$item> {
+                match self.len_ne().get() {
+                    1 => None,
+                    // Safety:
+                    // - at least one element remains
+                    _ => unsafe { self.as_mut_vec() }.pop(),
+                }
+            }
+            /// Returns a [`NonEmpty`] iterator.
+            pub fn into_iter_ne(self) -> $crate::NonEmpty<$into_iter> {
+                $crate::NonEmpty {
+                    inner: self.into_vec().into_iter(),
+                }
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::Deref for $crate::NonEmpty<$vec> {
+            type Target = $crate::Slice<$item>;
+
+            fn deref(&self) -> &Self::Target {
+                self.as_slice_ne()
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $crate::NonEmpty<$vec> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                self.as_mut_slice_ne()
+            }
+        }
+
+        $crate::as_ref_as_mut! {
+            <$($generics)*> for $crate::NonEmpty<$vec> as [$item];
+            <$($generics)*> for $crate::NonEmpty<$vec> as $crate::Slice<$item>;
+        }
+
+        $crate::borrow_borrow_mut! {
+            <$($generics)*> for $crate::NonEmpty<$vec> as [$item];
+            <$($generics)*> for $crate::NonEmpty<$vec> as $crate::Slice<$item>;
+        }
+
+        // iter
+        //-----
+
+        impl<$($generics)*> IntoIterator for $crate::NonEmpty<$vec> {
+            type Item = $item;
+            type IntoIter = $into_iter;
+            fn into_iter(self) -> Self::IntoIter {
+                self.into_vec().into_iter()
+            }
+        }
+        impl<'a, $($generics)*> IntoIterator for &'a $crate::NonEmpty<$vec> {
+            type Item = &'a $item;
+            type IntoIter = ::core::slice::Iter<'a, $item>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+        impl<'a, $($generics)*> IntoIterator for &'a mut $crate::NonEmpty<$vec> {
+            type Item = &'a mut $item;
+            type IntoIter = ::core::slice::IterMut<'a, $item>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+        impl<$($generics)*> Extend<$item> for $crate::NonEmpty<$vec> {
+            fn extend<II: IntoIterator<Item = $item>>(&mut self, iter: II) {
+                // Safety:
+                // - append-only operation
+                unsafe { self.as_mut_vec() }.extend(iter)
+            }
+        }
+        impl<$($generics)*> $crate::iter::FromNonEmptyIterator<$item> for $crate::NonEmpty<$vec> {
+            fn from_iter_ne<I>(iter: $crate::NonEmpty<I>) -> Self
+            where
+                I: Iterator<Item = $item>,
+            {
+                // Safety:
+                // - NonEmpty<impl Iterator> always yields at least one element
+                // - the inner vec panics rather than drop elements
+                unsafe { Self::new_unchecked(iter.relax().collect()) }
+            }
+        }
+
+        // partial_eq
+        //-----------
+
+        impl<U, $($generics)*> PartialEq<[U]> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialEq<U>,
+        {
+            fn eq(&self, other: &[U]) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+        impl<U, const N: usize, $($generics)*> PartialEq<[U; N]> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialEq<U>,
+        {
+            fn eq(&self, other: &[U; N]) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+        impl<U, $($generics)*> PartialEq<$crate::Slice<U>> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialEq<U>,
+        {
+            fn eq(&self, other: &$crate::Slice<U>) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+        impl<U, $($generics)*> PartialEq<$crate::NonEmpty<$vec>> for [U]
+        where
+            U: PartialEq<$item>,
+        {
+            fn eq(&self, other: &$crate::NonEmpty<$vec>) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+        impl<U, const N: usize, $($generics)*> PartialEq<$crate::NonEmpty<$vec>> for [U; N]
+        where
+            U: PartialEq<$item>,
+        {
+            fn eq(&self, other: &$crate::NonEmpty<$vec>) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+        impl<U, $($generics)*> PartialEq<$crate::NonEmpty<$vec>> for $crate::Slice<U>
+        where
+            U: PartialEq<$item>,
+        {
+            fn eq(&self, other: &$crate::NonEmpty<$vec>) -> bool {
+                <[_] as PartialEq<[_]>>::eq(self, other)
+            }
+        }
+
+        // cmp
+        //----
+
+        impl<$($generics)*> PartialOrd for $crate::NonEmpty<$vec>
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd>::partial_cmp(self, other)
+            }
+        }
+        impl<$($generics)*> Ord for $crate::NonEmpty<$vec>
+        where
+            $item: Ord,
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                <[_] as Ord>::cmp(self, other)
+            }
+        }
+        impl<$($generics)*> PartialOrd<[$item]> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &[$item]) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+        impl<const N: usize, $($generics)*> PartialOrd<[$item; N]> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &[$item; N]) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+        impl<$($generics)*> PartialOrd<$vec> for $crate::NonEmpty<$vec>
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &$vec) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+        impl<$($generics)*> PartialOrd<$crate::NonEmpty<$vec>> for [$item]
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &$crate::NonEmpty<$vec>) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+        impl<const N: usize, $($generics)*> PartialOrd<$crate::NonEmpty<$vec>> for [$item; N]
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &$crate::NonEmpty<$vec>) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+        impl<$($generics)*> PartialOrd<$crate::NonEmpty<$vec>> for $vec
+        where
+            $item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &$crate::NonEmpty<$vec>) -> Option<::core::cmp::Ordering> {
+                <[_] as PartialOrd<[_]>>::partial_cmp(self, other)
+            }
+        }
+
+        // convert
+        //--------
+
+        impl<$($generics)*> TryFrom<$vec> for $crate::NonEmpty<$vec> {
+            type Error = $vec;
+
+            fn try_from(value: $vec) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+        impl<'a, $($generics)*> TryFrom<&'a $vec> for &'a $crate::NonEmpty<$vec> {
+            type Error = $crate::Error;
+
+            fn try_from(value: &'a $vec) -> Result<Self, Self::Error> {
+                $crate::NonEmpty::<$vec>::new_ref(value).ok_or($crate::Error(()))
+            }
+        }
+        impl<'a, $($generics)*> TryFrom<&'a mut $vec> for &'a mut $crate::NonEmpty<$vec> {
+            type Error = $crate::Error;
+
+            fn try_from(value: &'a mut $vec) -> Result<Self, Self::Error> {
+                $crate::NonEmpty::<$vec>::new_mut(value).ok_or($crate::Error(()))
+            }
+        }
+        impl<$($generics)*> From<$crate::NonEmpty<$vec>> for $vec {
+            fn from(value: $crate::NonEmpty<$vec>) -> Self {
+                value.into_vec()
+            }
+        }
+    };
+}
+#[cfg(any(feature = "arrayvec07", feature = "smallvec1", feature = "tinyvec1"))]
+pub(crate) use vec_like;
+
 #[track_caller]
 const unsafe fn non_zero_usize(n: usize) -> NonZeroUsize {
     match NonZeroUsize::new(n) {
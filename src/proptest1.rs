@@ -1,29 +1,122 @@
 #![cfg(feature = "alloc")]
+//! Strategies for [`proptest`](proptest1).
+//!
+//! These never generate (or shrink to) empty collections,
+//! so never reject a case.
+
 use core::fmt::Debug;
 
-use crate::{Slice, Vec};
+use crate::{array_vec::AssertCap, Array, Slice, Vec};
 
 use proptest1::{
-    arbitrary::Arbitrary,
+    arbitrary::{any_with, Arbitrary},
+    array::UniformArrayStrategy,
+    collection,
     sample::SizeRange,
-    strategy::{FilterMap, Map, Strategy as _},
+    strategy::{Map, NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
 };
 
+/// Create a strategy to generate [`Vec`]s containing elements drawn from
+/// `element` and with a size range given by `size`.
+///
+/// A lower bound of zero in `size` is treated as one.
+///
+/// # Panics
+/// - If `size` only admits a length of zero.
+pub fn vec<S: Strategy>(element: S, size: impl Into<SizeRange>) -> VecStrategy<S> {
+    let (start, end) = size.into().start_end_incl();
+    assert_ne!(end, 0, "size range must admit a non-zero length");
+    VecStrategy {
+        inner: collection::vec(element, start.max(1)..=end),
+    }
+}
+
+/// Create a strategy to generate [`Box<Slice<T>>`]s.
+///
+/// See [`vec()`].
+pub fn slice<S: Strategy>(element: S, size: impl Into<SizeRange>) -> SliceStrategy<S> {
+    vec(element, size).prop_map(Vec::into_boxed_slice as fn(_) -> _)
+}
+
+/// Create a strategy to generate [`Array`]s, with each element drawn from
+/// `element`.
+///
+/// ```compile_fail
+/// # use proptest1::strategy::Just;
+/// nunny::proptest::array::<_, 0>(Just(1));
+/// ```
+pub fn array<S: Strategy, const N: usize>(element: S) -> ArrayStrategy<S, N> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertCap::<N, 1>::NONZERO;
+    UniformArrayStrategy::new(element).prop_map(Array::new_nonzero as fn(_) -> _)
+}
+
+/// Strategy returned by [`slice()`].
+pub type SliceStrategy<S> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> Box<Slice<<S as Strategy>::Value>>>;
+
+/// Strategy returned by [`array()`].
+pub type ArrayStrategy<S, const N: usize> = Map<
+    UniformArrayStrategy<S, [<S as Strategy>::Value; N]>,
+    fn([<S as Strategy>::Value; N]) -> Array<<S as Strategy>::Value, N>,
+>;
+
+/// Strategy returned by [`vec()`].
+#[derive(Debug, Clone)]
+#[must_use = "strategies do nothing unless used"]
+pub struct VecStrategy<S: Strategy> {
+    inner: collection::VecStrategy<S>,
+}
+
+impl<S: Strategy> Strategy for VecStrategy<S> {
+    type Tree = VecValueTree<S::Tree>;
+    type Value = Vec<S::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.inner
+            .new_tree(runner)
+            .map(|inner| VecValueTree { inner })
+    }
+}
+
+/// [`ValueTree`] corresponding to [`VecStrategy`].
+///
+/// Shrinks by removing elements (but never the last one),
+/// and then by shrinking each element.
+#[derive(Debug, Clone)]
+pub struct VecValueTree<T: ValueTree> {
+    inner: collection::VecValueTree<T>,
+}
+
+impl<T: ValueTree> ValueTree for VecValueTree<T> {
+    type Value = Vec<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        match Vec::new(self.inner.current()) {
+            Ok(it) => it,
+            Err(_) => unreachable!("minimum size is non-zero"),
+        }
+    }
+    fn simplify(&mut self) -> bool {
+        self.inner.simplify()
+    }
+    fn complicate(&mut self) -> bool {
+        self.inner.complicate()
+    }
+}
+
 impl<T> Arbitrary for Vec<T>
 where
     T: Arbitrary + Debug,
 {
     type Parameters = (SizeRange, <T as Arbitrary>::Parameters);
 
-    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        alloc::vec::Vec::<T>::arbitrary_with(args)
-            .prop_filter_map("vec was empty", |it| Vec::new(it).ok())
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        vec(any_with::<T>(args), size)
     }
 
-    type Strategy = FilterMap<
-        <alloc::vec::Vec<T> as Arbitrary>::Strategy,
-        fn(alloc::vec::Vec<T>) -> Option<Vec<T>>,
-    >;
+    type Strategy = VecStrategy<T::Strategy>;
 }
 
 impl<T> Arbitrary for Box<Slice<T>>
@@ -32,9 +125,50 @@ where
 {
     type Parameters = (SizeRange, <T as Arbitrary>::Parameters);
 
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        slice(any_with::<T>(args), size)
+    }
+
+    type Strategy = SliceStrategy<T::Strategy>;
+}
+
+impl<T, const N: usize> Arbitrary for Array<T, N>
+where
+    T: Arbitrary + Debug,
+{
+    type Parameters = <T as Arbitrary>::Parameters;
+
     fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        Vec::arbitrary_with(args).prop_map(Into::into)
+        array(any_with::<T>(args))
+    }
+
+    type Strategy = ArrayStrategy<T::Strategy, N>;
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest1::arbitrary::any;
+
+    use super::*;
+
+    #[test]
+    fn shrink() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..32 {
+            let mut tree = vec(any::<u8>(), 0..8).new_tree(&mut runner).unwrap();
+            while tree.simplify() {
+                assert!(tree.current().len_ne().get() <= 8);
+            }
+            assert_eq!(tree.current(), [0]);
+        }
     }
 
-    type Strategy = Map<<Vec<T> as Arbitrary>::Strategy, fn(Vec<T>) -> Box<Slice<T>>>;
+    #[test]
+    fn arbitrary() {
+        let mut runner = TestRunner::deterministic();
+        let tree = any::<Array<u8, 2>>().new_tree(&mut runner).unwrap();
+        assert_eq!(tree.current().len(), 2);
+        let tree = any::<Box<Slice<u8>>>().new_tree(&mut runner).unwrap();
+        assert!(!tree.current().is_empty());
+    }
 }
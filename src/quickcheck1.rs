@@ -1,18 +1,25 @@
-use crate::{Slice, Vec};
-use quickcheck1::Arbitrary;
+//! Generation and shrinking with [`quickcheck`](quickcheck1).
+//!
+//! [`Vec`]s and `Box<Slice<T>>`s shrink by removing elements, down to a
+//! single element, and then by shrinking each element in place.
+//! [`Array`]s shrink element-wise.
+
+use core::{array, iter};
+
+use crate::{Array, Slice, Vec};
+use quickcheck1::{Arbitrary, Gen};
 
 impl<T> Arbitrary for Vec<T>
 where
     T: Arbitrary,
 {
-    fn arbitrary(g: &mut quickcheck1::Gen) -> Self {
+    fn arbitrary(g: &mut Gen) -> Self {
         let mut it = Vec::of(T::arbitrary(g));
         it.extend(alloc::vec::Vec::arbitrary(g));
         it
     }
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let raw = self.clone().into_vec();
-        Box::new(Arbitrary::shrink(&raw).flat_map(Vec::new))
+        Box::new(removals(self.clone()).chain(elements(self.clone())))
     }
 }
 
@@ -20,13 +27,77 @@ impl<T> Arbitrary for Box<Slice<T>>
 where
     T: Arbitrary,
 {
-    fn arbitrary(g: &mut quickcheck1::Gen) -> Self {
-        let mut it = Vec::of(T::arbitrary(g));
-        it.extend(alloc::vec::Vec::arbitrary(g));
-        it.into_boxed_slice()
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::arbitrary(g).into_boxed_slice()
     }
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let raw = Vec::from(self.clone());
-        Box::new(Arbitrary::shrink(&raw).map(Self::from))
+        Box::new(Vec::from(self.clone()).shrink().map(Vec::into_boxed_slice))
+    }
+}
+
+impl<T, const N: usize> Arbitrary for Array<T, N>
+where
+    T: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Array::new_nonzero(array::from_fn(|_| T::arbitrary(g)))
+    }
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(elements(self.clone()))
+    }
+}
+
+/// Remove progressively smaller chunks of `src`, never removing every element.
+fn removals<T: Clone + 'static>(src: Vec<T>) -> impl Iterator<Item = Vec<T>> {
+    let len = src.len();
+    iter::successors(Some(len / 2), |chunk| Some(chunk / 2))
+        .take_while(|chunk| *chunk != 0)
+        .flat_map(move |chunk| {
+            let src = src.clone();
+            (0..len).step_by(chunk).map(move |start| {
+                let mut it = src.clone();
+                // Safety:
+                // - `chunk` is at most half of `len`, so at least one element remains
+                unsafe { it.as_mut_vec() }.drain(start..len.min(start + chunk));
+                it
+            })
+        })
+}
+
+/// Shrink each element of `src` in turn, leaving the others unchanged.
+fn elements<C, T>(src: C) -> impl Iterator<Item = C>
+where
+    C: AsRef<[T]> + AsMut<[T]> + Clone + 'static,
+    T: Arbitrary,
+{
+    (0..src.as_ref().len()).flat_map(move |ix| {
+        let src = src.clone();
+        src.as_ref()[ix].shrink().map(move |shrunk| {
+            let mut it = src.clone();
+            it.as_mut()[ix] = shrunk;
+            it
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink() {
+        let v = Vec::of_extending(3u8, [1, 4, 1, 5]);
+        assert!(v.shrink().all(|it| it != v));
+
+        // always follow the first candidate
+        let mut current = v;
+        while let Some(next) = current.shrink().next() {
+            current = next;
+        }
+        assert_eq!(current, [0]);
+
+        let array = Array::new([2u8, 0]).unwrap();
+        assert!(array.shrink().all(|it| it[1] == 0));
+        assert!(Box::<Slice<u8>>::from(Vec::of(0)).shrink().next().is_none());
     }
 }
@@ -0,0 +1,160 @@
+//! Random sampling with [`rand`](rand09).
+//!
+//! Unlike [`IndexedRandom`], choosing from a [`Slice`] never fails.
+
+#[cfg(feature = "alloc")]
+use core::num::NonZeroUsize;
+
+use rand09::{
+    distr::{slice::Choose, Distribution, StandardUniform},
+    seq::{IndexedMutRandom, IndexedRandom, SliceRandom},
+    Rng,
+};
+#[cfg(feature = "alloc")]
+use rand09::{
+    distr::{
+        uniform::{SampleBorrow, SampleUniform},
+        weighted::Weight,
+    },
+    seq::{SliceChooseIter, WeightError},
+};
+
+use crate::{Array, Slice};
+
+/// Random sampling.
+impl<T> Slice<T> {
+    /// [`NonEmpty`](crate::NonEmpty) version of [`IndexedRandom::choose`].
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        match self.as_slice().choose(rng) {
+            Some(it) => it,
+            // Safety:
+            // - cannot create empty slice without `unsafe`
+            None => unsafe { crate::unreachable() },
+        }
+    }
+    /// [`NonEmpty`](crate::NonEmpty) version of [`IndexedMutRandom::choose_mut`].
+    pub fn choose_mut<R: Rng + ?Sized>(&mut self, rng: &mut R) -> &mut T {
+        match self.as_mut_slice().choose_mut(rng) {
+            Some(it) => it,
+            // Safety:
+            // - cannot create empty slice without `unsafe`
+            None => unsafe { crate::unreachable() },
+        }
+    }
+    /// Like [`IndexedRandom::choose_weighted`].
+    ///
+    /// Only fails if the weights are invalid,
+    /// e.g if they are negative, or all zero.
+    #[cfg(feature = "alloc")]
+    pub fn choose_weighted<R, F, B, X>(&self, rng: &mut R, weight: F) -> Result<&T, WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&T) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform + Weight + PartialOrd<X>,
+    {
+        self.as_slice().choose_weighted(rng, weight)
+    }
+    /// [`NonEmpty`](crate::NonEmpty) version of [`IndexedRandom::choose_multiple`].
+    ///
+    /// Yields `amount` distinct elements, or every element if `amount`
+    /// exceeds the length of the slice.
+    #[cfg(feature = "alloc")]
+    pub fn choose_multiple_ne<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: NonZeroUsize,
+    ) -> crate::NonEmpty<SliceChooseIter<'_, [T], T>> {
+        crate::NonEmpty {
+            inner: self.as_slice().choose_multiple(rng, amount.get()),
+        }
+    }
+    /// Shuffle the slice in place, see [`SliceRandom::shuffle`].
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.as_mut_slice().shuffle(rng)
+    }
+}
+
+/// Sample uniformly from the elements of the slice, which cannot fail.
+///
+/// ```
+/// # use rand09::{distr::slice::Choose, Rng};
+/// fn play(rng: &mut impl Rng) -> &'static str {
+///     rng.sample(Choose::from(nunny::slice!["rock", "paper", "scissors"]))
+/// }
+/// ```
+impl<'a, T> From<&'a Slice<T>> for Choose<'a, T> {
+    fn from(value: &'a Slice<T>) -> Self {
+        match Choose::new(value.as_slice()) {
+            Ok(it) => it,
+            // Safety:
+            // - cannot create empty slice without `unsafe`
+            Err(_) => unsafe { crate::unreachable() },
+        }
+    }
+}
+
+impl<T, const N: usize> Distribution<Array<T, N>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Array<T, N> {
+        Array::new_nonzero(core::array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand09::RngCore;
+
+    use super::*;
+
+    /// Deterministic xorshift generator.
+    struct TestRng(u64);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for chunk in dst.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+    }
+
+    #[test]
+    fn choose() {
+        let mut rng = TestRng(0x2545_f491_4f6c_dd1d);
+        let mut array = Array::new([1, 2, 3]).unwrap();
+        assert!(array.contains(array.choose(&mut rng)));
+        assert!(array.contains(rng.sample(Choose::from(array.as_slice_ne()))));
+        *array.choose_mut(&mut rng) = 0;
+        assert!(array.contains(&0));
+        array.shuffle(&mut rng);
+        array.sort();
+        assert_eq!(array[0], 0);
+        let _: Array<u8, 4> = rng.random();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_weighted() {
+        let mut rng = TestRng(0x2545_f491_4f6c_dd1d);
+        let slice = Slice::new(&[(1, 0.0), (2, 1.0)]).unwrap();
+        assert_eq!(slice.choose_weighted(&mut rng, |it| it.1).unwrap().0, 2);
+        assert!(slice.choose_weighted(&mut rng, |_| 0.0).is_err());
+        let amount = NonZeroUsize::new(5).unwrap();
+        assert_eq!(
+            slice.choose_multiple_ne(&mut rng, amount).relax().count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Parallel iteration with [`rayon`](rayon1).
+
+use rayon1::{
+    iter::{IntoParallelIterator as _, IntoParallelRefIterator as _, Map, ParallelIterator},
+    slice, vec,
+};
+
+use crate::{NonEmpty, Slice, Vec};
+
+/// Known non-empty parallel iterator for [`Slice`].
+impl<T> Slice<T>
+where
+    T: Sync,
+{
+    /// [`NonEmpty`] version of [`IntoParallelRefIterator::par_iter`](rayon1::iter::IntoParallelRefIterator).
+    /// ```
+    /// # use nunny::{rayon::NonEmptyParallelIterator as _, slice};
+    /// let max = slice![1, 2, 3].par_iter_ne().max();
+    /// assert_eq!(*max, 3);
+    /// ```
+    pub fn par_iter_ne(&self) -> NonEmpty<slice::Iter<'_, T>> {
+        NonEmpty {
+            inner: self.as_slice().par_iter(),
+        }
+    }
+}
+
+/// Known non-empty parallel iterator for [`Vec`].
+impl<T> Vec<T>
+where
+    T: Send,
+{
+    /// [`NonEmpty`] version of [`IntoParallelIterator::into_par_iter`](rayon1::iter::IntoParallelIterator).
+    pub fn into_par_iter_ne(self) -> NonEmpty<vec::IntoIter<T>> {
+        NonEmpty {
+            inner: self.into_vec().into_par_iter(),
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl<P> Sealed for crate::NonEmpty<P> where P: rayon1::iter::ParallelIterator {}
+}
+
+/// Methods on [`ParallelIterator`]s with a non-empty invariant.
+///
+/// These mirror the methods on sequential [`NonEmpty`] iterators,
+/// but live on a trait to avoid clashing with them.
+pub trait NonEmptyParallelIterator: sealed::Sealed + Sized {
+    /// The wrapped [`ParallelIterator`].
+    type Inner: ParallelIterator<Item = Self::Item>;
+    /// The type of item yielded by the iterator.
+    type Item: Send;
+
+    /// [`NonEmpty`] version of [`ParallelIterator::map`].
+    fn map<R, F>(self, f: F) -> NonEmpty<Map<Self::Inner, F>>
+    where
+        F: Fn(Self::Item) -> R + Sync + Send,
+        R: Send;
+    /// [`NonEmpty`] version of [`ParallelIterator::reduce_with`],
+    /// which needs no identity.
+    /// ```
+    /// # use nunny::{rayon::NonEmptyParallelIterator as _, vec};
+    /// let sum = vec![1, 2, 3].into_par_iter_ne().reduce(|l, r| l + r);
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: Fn(Self::Item, Self::Item) -> Self::Item + Sync + Send;
+    /// [`NonEmpty`] version of [`ParallelIterator::max`].
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord;
+    /// [`NonEmpty`] version of [`ParallelIterator::min`].
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord;
+    /// [`NonEmpty`] version of [`ParallelIterator::max_by_key`].
+    fn max_by_key<K, F>(self, f: F) -> Self::Item
+    where
+        K: Ord + Send,
+        F: Fn(&Self::Item) -> K + Sync + Send;
+    /// [`NonEmpty`] version of [`ParallelIterator::min_by_key`].
+    fn min_by_key<K, F>(self, f: F) -> Self::Item
+    where
+        K: Ord + Send,
+        F: Fn(&Self::Item) -> K + Sync + Send;
+    /// Collect this iterator into a [`NonEmpty`] [`Vec`].
+    fn collect_vec(self) -> Vec<Self::Item>;
+    /// Remove the [`NonEmpty`] wrapper.
+    fn relax(self) -> Self::Inner;
+}
+
+macro_rules! unwrap {
+    ($expr:expr) => {
+        match $expr {
+            Some(it) => it,
+            // Safety:
+            // - NonEmpty<impl ParallelIterator> is only constructed from known NonEmpty items
+            // - NonEmpty<impl ParallelIterator> does not give out mutable access to the inner iterator
+            None => unsafe { crate::unreachable() },
+        }
+    };
+}
+
+impl<P> NonEmptyParallelIterator for NonEmpty<P>
+where
+    P: ParallelIterator,
+{
+    type Inner = P;
+    type Item = P::Item;
+
+    fn map<R, F>(self, f: F) -> NonEmpty<Map<P, F>>
+    where
+        F: Fn(P::Item) -> R + Sync + Send,
+        R: Send,
+    {
+        NonEmpty {
+            inner: self.inner.map(f),
+        }
+    }
+    fn reduce<F>(self, f: F) -> P::Item
+    where
+        F: Fn(P::Item, P::Item) -> P::Item + Sync + Send,
+    {
+        unwrap!(self.inner.reduce_with(f))
+    }
+    fn max(self) -> P::Item
+    where
+        P::Item: Ord,
+    {
+        unwrap!(self.inner.max())
+    }
+    fn min(self) -> P::Item
+    where
+        P::Item: Ord,
+    {
+        unwrap!(self.inner.min())
+    }
+    fn max_by_key<K, F>(self, f: F) -> P::Item
+    where
+        K: Ord + Send,
+        F: Fn(&P::Item) -> K + Sync + Send,
+    {
+        unwrap!(self.inner.max_by_key(f))
+    }
+    fn min_by_key<K, F>(self, f: F) -> P::Item
+    where
+        K: Ord + Send,
+        F: Fn(&P::Item) -> K + Sync + Send,
+    {
+        unwrap!(self.inner.min_by_key(f))
+    }
+    fn collect_vec(self) -> Vec<P::Item> {
+        // Safety:
+        // - NonEmpty<impl ParallelIterator> is only constructed from known NonEmpty items
+        unsafe { Vec::new_unchecked(self.inner.collect()) }
+    }
+    fn relax(self) -> P {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel() {
+        let v = Vec::of_extending(3, [1, 4, 1, 5]);
+        assert_eq!(*v.par_iter_ne().max(), 5);
+        assert_eq!(*v.par_iter_ne().min(), 1);
+        assert_eq!(*v.par_iter_ne().max_by_key(|it| **it % 4), 3);
+        assert_eq!(
+            v.par_iter_ne().map(|it| it * 2).collect_vec(),
+            [6, 2, 8, 2, 10]
+        );
+        assert_eq!(v.into_par_iter_ne().reduce(|l, r| l * r), 60);
+    }
+}
@@ -0,0 +1,277 @@
+//! Zero-copy (de)serialization with [`rkyv`](rkyv08).
+//!
+//! [`Vec`] and `Box<Slice<T>>` are archived as an [`ArchivedVec`],
+//! which dereferences to a [`Slice`].
+//! [`Array`]s are archived as [`Array`]s of the archived element type.
+//!
+//! Validation rejects zero-length archives.
+
+use core::{fmt, ops::Deref};
+
+use alloc::boxed::Box;
+use rkyv08::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::VecResolver,
+    Archive, Deserialize, DeserializeUnsized, Place, Portable, Serialize,
+};
+
+use crate::{Array, Error, Slice, Vec};
+
+/// An archived [`Vec`] or `Box<Slice<T>>`.
+///
+/// This is a [`rkyv::vec::ArchivedVec`](rkyv08::vec::ArchivedVec)
+/// with a length of at least one.
+#[repr(transparent)]
+pub struct ArchivedVec<T> {
+    inner: rkyv08::vec::ArchivedVec<T>,
+}
+
+// SAFETY: `ArchivedVec` is `#[repr(transparent)]` over a `Portable` type.
+unsafe impl<T: Portable> Portable for ArchivedVec<T> {}
+
+impl<T> ArchivedVec<T> {
+    /// Returns a [`Slice`] of the archived elements.
+    pub fn as_slice_ne(&self) -> &Slice<T> {
+        // SAFETY: we are only constructed by serializing a non-empty collection,
+        //         or by validation
+        unsafe { Slice::new_unchecked(self.inner.as_slice()) }
+    }
+    /// Returns the underlying [`rkyv::vec::ArchivedVec`](rkyv08::vec::ArchivedVec).
+    pub fn as_archived_vec(&self) -> &rkyv08::vec::ArchivedVec<T> {
+        &self.inner
+    }
+}
+
+impl<T> Deref for ArchivedVec<T> {
+    type Target = Slice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_ne()
+    }
+}
+
+impl<T> fmt::Debug for ArchivedVec<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice_ne().fmt(f)
+    }
+}
+
+impl<T, U> PartialEq<Slice<U>> for ArchivedVec<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Slice<U>) -> bool {
+        self.as_slice_ne() == other
+    }
+}
+
+impl<T, U> PartialEq<Vec<U>> for ArchivedVec<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice_ne() == other.as_slice_ne()
+    }
+}
+
+// SAFETY: we check the inner vector, and then our own invariant
+unsafe impl<T, C> CheckBytes<C> for ArchivedVec<T>
+where
+    rkyv08::vec::ArchivedVec<T>: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        rkyv08::vec::ArchivedVec::<T>::check_bytes(value.cast(), context)?;
+        match (*value).inner.is_empty() {
+            true => Err(C::Error::new(Error(()))),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T> Archive for Vec<T>
+where
+    T: Archive,
+{
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        resolve(self, resolver, out)
+    }
+}
+
+impl<T, S> Serialize<S> for Vec<T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<T, D> Deserialize<Vec<T>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Vec<T>, D::Error> {
+        let src: alloc::vec::Vec<T> = self.inner.deserialize(deserializer)?;
+        Vec::new(src).map_err(|_| D::Error::new(Error(())))
+    }
+}
+
+impl<T> Archive for Box<Slice<T>>
+where
+    T: Archive,
+{
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        resolve(self, resolver, out)
+    }
+}
+
+impl<T, S> Serialize<S> for Box<Slice<T>>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<T, D> Deserialize<Box<Slice<T>>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Box<Slice<T>>, D::Error> {
+        Deserialize::<Vec<T>, D>::deserialize(self, deserializer).map(Vec::into_boxed_slice)
+    }
+}
+
+fn resolve<T: Archive>(
+    slice: &Slice<T>,
+    resolver: VecResolver,
+    out: Place<ArchivedVec<T::Archived>>,
+) {
+    // SAFETY: `ArchivedVec` is #[repr(transparent)]
+    let out = unsafe { out.cast_unchecked() };
+    rkyv08::vec::ArchivedVec::resolve_from_slice(slice.as_slice(), resolver, out)
+}
+
+fn serialize<T, S>(slice: &Slice<T>, serializer: &mut S) -> Result<VecResolver, S::Error>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    rkyv08::vec::ArchivedVec::serialize_from_slice(slice.as_slice(), serializer)
+}
+
+// SAFETY: `Array` is `#[repr(transparent)]` over `[T; N]`, which is `Portable`.
+unsafe impl<T: Portable, const N: usize> Portable for Array<T, N> {}
+
+impl<T, const N: usize> Archive for Array<T, N>
+where
+    T: Archive,
+{
+    type Archived = Array<T::Archived, N>;
+    type Resolver = [T::Resolver; N];
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: we are #[repr(transparent)]
+        self.as_array()
+            .resolve(resolver, unsafe { out.cast_unchecked() })
+    }
+}
+
+impl<T, S, const N: usize> Serialize<S> for Array<T, N>
+where
+    T: Serialize<S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.as_array().serialize(serializer)
+    }
+}
+
+impl<T, D, const N: usize> Deserialize<Array<T, N>, D> for Array<T::Archived, N>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Array<T, N>, D::Error> {
+        Array::new(self.as_array().deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::new(Error(())))
+    }
+}
+
+// SAFETY: we check the inner array, and then our own invariant
+unsafe impl<T, C, const N: usize> CheckBytes<C> for Array<T, N>
+where
+    [T; N]: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        <[T; N]>::check_bytes(value.cast(), context)?;
+        match N {
+            0 => Err(C::Error::new(Error(()))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv08::{access, deserialize, rancor, to_bytes, util::AlignedVec};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let v = Vec::of_extending(1u32, [2, 3]);
+        let bytes = to_bytes::<rancor::Error>(&v).unwrap();
+        let archived = access::<ArchivedVec<rkyv08::Archived<u32>>, rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.first(), &1);
+        assert_eq!(archived.last(), &3);
+        assert_eq!(archived.len_ne().get(), 3);
+        assert_eq!(deserialize::<Vec<u32>, rancor::Error>(archived).unwrap(), v);
+
+        let boxed = v.clone().into_boxed_slice();
+        let bytes = to_bytes::<rancor::Error>(&boxed).unwrap();
+        let archived = access::<ArchivedVec<rkyv08::Archived<u32>>, rancor::Error>(&bytes).unwrap();
+        let unarchived: Box<Slice<u32>> = deserialize::<_, rancor::Error>(archived).unwrap();
+        assert_eq!(*unarchived, *v);
+
+        let array = Array::new([1u8, 2]).unwrap();
+        let bytes = to_bytes::<rancor::Error>(&array).unwrap();
+        let archived = access::<Array<u8, 2>, rancor::Error>(&bytes).unwrap();
+        assert_eq!(
+            deserialize::<Array<u8, 2>, rancor::Error>(archived).unwrap(),
+            array
+        );
+    }
+
+    #[test]
+    fn reject_empty() {
+        let bytes: AlignedVec = to_bytes::<rancor::Error>(&std::vec::Vec::<u32>::new()).unwrap();
+        assert!(access::<ArchivedVec<rkyv08::Archived<u32>>, rancor::Error>(&bytes).is_err());
+    }
+}
@@ -1,10 +1,38 @@
-use crate::{Array, Slice, Vec};
+//! JSON Schema support with [`schemars`](schemars08).
+//!
+//! Schemas match those of the possibly-empty equivalents,
+//! with a `minItems` of `1` (or `N`, with a `maxItems` of `N` for [`Array`]).
+//! Like `schemars`'s own `[T; N]`, [`Array`] uses `items` rather than a
+//! tuple of `items`, since every element has the same schema.
+//!
+//! [`Vec`], [`Slice`] and [`HeadVec`] share a schema named
+//! `NonEmpty_Array_of_{item}`, and [`Array`] is named `Array_of_{N}_of_{item}`.
+//! To choose another name, e.g to avoid clashes in an OpenAPI document,
+//! wrap the collection in a newtype with `#[schemars(rename = "...")]`.
+
+use std::borrow::Cow;
+
+use crate::{Array, ArrayVec, HeadVec, Slice, Vec};
 use schemars08::{
     gen::SchemaGenerator,
     schema::{ArrayValidation, InstanceType, Schema, SchemaObject, SingleOrVec},
     JsonSchema,
 };
 
+fn array_schema(items: Schema, min_items: usize, max_items: Option<usize>) -> Schema {
+    let clamp = |it| u32::try_from(it).unwrap_or(u32::MAX);
+    Schema::Object(SchemaObject {
+        instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Array))),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(SingleOrVec::Single(Box::new(items))),
+            min_items: Some(clamp(min_items)),
+            max_items: max_items.map(clamp),
+            ..Default::default()
+        })),
+        ..Default::default()
+    })
+}
+
 impl<T> JsonSchema for Vec<T>
 where
     T: JsonSchema,
@@ -13,51 +41,70 @@ where
         format!("NonEmpty_Array_of_{}", T::schema_name())
     }
 
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::Vec<{}>", T::schema_id()).into()
+    }
+
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
-        Schema::Object(SchemaObject {
-            instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Array))),
-            array: Some(Box::new(ArrayValidation {
-                items: Some(SingleOrVec::Single(Box::new(gen.subschema_for::<T>()))),
-                min_items: Some(1),
-                ..Default::default()
-            })),
-            ..Default::default()
-        })
+        array_schema(gen.subschema_for::<T>(), 1, None)
     }
 }
 
-impl<T> JsonSchema for Box<Slice<T>>
+/// Implement [`JsonSchema`] by delegating to [`Vec<T>`].
+macro_rules! like_vec {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<T> JsonSchema for $ty
+        where
+            T: JsonSchema,
+        {
+            fn schema_name() -> String {
+                Vec::<T>::schema_name()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                Vec::<T>::schema_id()
+            }
+
+            fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                Vec::<T>::json_schema(gen)
+            }
+        }
+    )*};
+}
+
+like_vec!(Slice<T>, HeadVec<T>);
+
+impl<T, const N: usize> JsonSchema for Array<T, N>
 where
     T: JsonSchema,
 {
     fn schema_name() -> String {
-        Vec::<T>::schema_name()
+        format!("Array_of_{}_of_{}", N, T::schema_name())
     }
 
-    fn json_schema(gen: &mut schemars08::gen::SchemaGenerator) -> Schema {
-        Vec::<T>::json_schema(gen)
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::Array<{}, {}>", T::schema_id(), N).into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        array_schema(gen.subschema_for::<T>(), N, Some(N))
     }
 }
 
-impl<T, const N: usize> JsonSchema for Array<T, N>
+impl<T, const CAP: usize> JsonSchema for ArrayVec<T, CAP>
 where
     T: JsonSchema,
 {
     fn schema_name() -> String {
-        format!("Array_of_{}_of_{}", N, T::schema_name())
+        format!("NonEmpty_Array_of_up_to_{}_of_{}", CAP, T::schema_name())
     }
 
-    fn json_schema(gen: &mut schemars08::gen::SchemaGenerator) -> Schema {
-        Schema::Object(SchemaObject {
-            instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Array))),
-            array: Some(Box::new(ArrayValidation {
-                items: Some(SingleOrVec::Single(Box::new(gen.subschema_for::<T>()))),
-                min_items: Some(u32::try_from(N).unwrap_or(u32::MAX)),
-                max_items: Some(u32::try_from(N).unwrap_or(u32::MAX)),
-                ..Default::default()
-            })),
-            ..Default::default()
-        })
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::ArrayVec<{}, {}>", T::schema_id(), CAP).into()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        array_schema(gen.subschema_for::<T>(), 1, Some(CAP))
     }
 }
 
@@ -84,4 +131,21 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn test_array_vec_schema() {
+        let schema = schema_for!(ArrayVec<bool, 2>);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            serde_json::json!({
+              "$schema": "http://json-schema.org/draft-07/schema#",
+              "title": "NonEmpty_Array_of_up_to_2_of_Boolean",
+              "type": "array",
+              "items": { "type": "boolean" },
+              "minItems": 1,
+              "maxItems": 2
+            })
+        );
+        assert_eq!(Box::<Slice<u8>>::schema_id(), Vec::<u8>::schema_id());
+    }
 }
@@ -1,6 +1,19 @@
+//! JSON Schema support with [`schemars`](schemars09).
+//!
+//! Schemas match those of the possibly-empty equivalents,
+//! with a `minItems` of `1` (or `N`, with a `maxItems` of `N` for [`Array`]).
+//! Like `schemars`'s own `[T; N]`, [`Array`] uses `items` rather than
+//! `prefixItems`, since every element has the same schema,
+//! and `items` means the same thing in every draft `schemars` can target.
+//!
+//! [`Vec`], [`Slice`] and [`HeadVec`] share a schema named
+//! `NonEmpty_Array_of_{item}`, and [`Array`] is named `Array_of_{N}_of_{item}`.
+//! To choose another name, e.g to avoid clashes in an OpenAPI document,
+//! wrap the collection in a newtype with `#[schemars(rename = "...")]`.
+
 use std::borrow::Cow;
 
-use crate::{Array, Slice, Vec};
+use crate::{Array, ArrayVec, HeadVec, Slice, Vec};
 use schemars09::{json_schema, JsonSchema, Schema, SchemaGenerator};
 
 impl<T> JsonSchema for Vec<T>
@@ -11,6 +24,10 @@ where
         format!("NonEmpty_Array_of_{}", T::schema_name()).into()
     }
 
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::Vec<{}>", T::schema_id()).into()
+    }
+
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
         json_schema!({
             "type": "array",
@@ -20,33 +37,70 @@ where
     }
 }
 
-impl<T> JsonSchema for Box<Slice<T>>
+/// Implement [`JsonSchema`] by delegating to [`Vec<T>`].
+macro_rules! like_vec {
+    ($($ty:ty),* $(,)?) => {$(
+        impl<T> JsonSchema for $ty
+        where
+            T: JsonSchema,
+        {
+            fn schema_name() -> Cow<'static, str> {
+                Vec::<T>::schema_name()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                Vec::<T>::schema_id()
+            }
+
+            fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                Vec::<T>::json_schema(gen)
+            }
+        }
+    )*};
+}
+
+like_vec!(Slice<T>, HeadVec<T>);
+
+impl<T, const N: usize> JsonSchema for Array<T, N>
 where
     T: JsonSchema,
 {
     fn schema_name() -> Cow<'static, str> {
-        Vec::<T>::schema_name()
+        format!("Array_of_{}_of_{}", N, T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::Array<{}, {}>", T::schema_id(), N).into()
     }
 
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
-        Vec::<T>::json_schema(gen)
+        json_schema!({
+            "type": "array",
+            "items": gen.subschema_for::<T>(),
+            "minItems": N,
+            "maxItems": N,
+        })
     }
 }
 
-impl<T, const N: usize> JsonSchema for Array<T, N>
+impl<T, const CAP: usize> JsonSchema for ArrayVec<T, CAP>
 where
     T: JsonSchema,
 {
     fn schema_name() -> Cow<'static, str> {
-        format!("Array_of_{}_of_{}", N, T::schema_name()).into()
+        format!("NonEmpty_Array_of_up_to_{}_of_{}", CAP, T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("nunny::ArrayVec<{}, {}>", T::schema_id(), CAP).into()
     }
 
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
         json_schema!({
             "type": "array",
             "items": gen.subschema_for::<T>(),
-            "minItems": N,
-            "maxItems": N,
+            "minItems": 1,
+            "maxItems": CAP,
         })
     }
 }
@@ -74,4 +128,25 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn test_array_schema() {
+        let schema = schema_for!(Array<bool, 2>);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            serde_json::json!({
+              "$schema": "https://json-schema.org/draft/2020-12/schema",
+              "title": "Array_of_2_of_boolean",
+              "type": "array",
+              "items": { "type": "boolean" },
+              "minItems": 2,
+              "maxItems": 2
+            })
+        );
+        assert_eq!(
+            schema_for!(ArrayVec<bool, 2>).get("maxItems"),
+            Some(&serde_json::json!(2))
+        );
+        assert_eq!(Box::<Slice<u8>>::schema_id(), Vec::<u8>::schema_id());
+    }
 }
@@ -1,18 +1,62 @@
-use serde1::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+//! Serialization and deserialization with [`serde`](serde1).
+//!
+//! Errors on deserialization report the received length.
+//!
+//! [`Array`]s are (de)serialized as fixed-size tuples, like `[T; N]`,
+//! and do not require the `alloc` feature.
+//!
+//! `Cow<'de, Slice<T>>` may be deserialized, through [`Vec`](crate::Vec)
+//! as the [`ToOwned::Owned`](alloc::borrow::ToOwned::Owned) type.
 
-use crate::Slice;
+use core::{fmt, marker::PhantomData};
+
+use serde1::{
+    de::{Error as _, Expected, SeqAccess, Visitor},
+    ser::SerializeTuple as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Array, ArrayVec, Slice};
 #[cfg(feature = "alloc")]
 use {
-    crate::{Error, Vec},
+    crate::{HeadVec, Vec},
     alloc::boxed::Box,
+    core::num::NonZeroUsize,
+    serde1::de::DeserializeSeed,
 };
 
+/// Expects a sequence with a length of at least one, or exactly `N`.
+struct Len(Option<usize>);
+
+impl Expected for Len {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(n) => write!(f, "a sequence of length {}", n),
+            None => f.write_str("a non-empty sequence"),
+        }
+    }
+}
+
 impl<'de: 'a, 'a> Deserialize<'de> for &'a Slice<u8> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        <&Slice<u8>>::try_from(<&[u8]>::deserialize(deserializer)?).map_err(D::Error::custom)
+        let src = <&[u8]>::deserialize(deserializer)?;
+        Slice::new(src).ok_or_else(|| D::Error::invalid_length(src.len(), &Len(None)))
+    }
+}
+
+impl<'de: 'a, 'a, const N: usize> Deserialize<'de> for &'a Array<u8, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let src = <&[u8]>::deserialize(deserializer)?;
+        <&[u8; N]>::try_from(src)
+            .ok()
+            .and_then(Array::new_ref)
+            .ok_or_else(|| D::Error::invalid_length(src.len(), &Len(Some(N))))
     }
 }
 
@@ -28,6 +72,73 @@ where
     }
 }
 
+impl<T, const N: usize> Serialize for Array<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for item in self.iter() {
+            tuple.serialize_element(item)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for Array<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Array<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array of length {}", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let Some(first) = seq.next_element()? else {
+                    return Err(A::Error::invalid_length(0, &self));
+                };
+                // drops already-deserialized elements on error
+                let mut buf = ArrayVec::<T, N>::of(first);
+                for len in 1..N {
+                    match seq.next_element()? {
+                        Some(item) => {
+                            // cannot fail, `len < N`
+                            let _ = buf.try_push(item);
+                        }
+                        None => return Err(A::Error::invalid_length(len, &self)),
+                    }
+                }
+                match buf.into_array() {
+                    Ok(it) => Ok(it),
+                    // Safety:
+                    // - N elements were pushed
+                    Err(_) => unsafe { crate::unreachable() },
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<'de, T> Deserialize<'de> for Box<Slice<T>>
@@ -38,7 +149,57 @@ where
     where
         D: Deserializer<'de>,
     {
-        <Box<Slice<T>>>::try_from(<Box<[T]>>::deserialize(deserializer)?).map_err(D::Error::custom)
+        Vec::deserialize(deserializer).map(Vec::into_boxed_slice)
+    }
+}
+
+/// Don't trust size hints further than this many bytes.
+#[cfg(feature = "alloc")]
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+/// A size hint that won't exhaust memory on malicious input.
+#[cfg(feature = "alloc")]
+fn cautious<T>(hint: Option<usize>) -> usize {
+    match core::mem::size_of::<T>() {
+        0 => 0,
+        size => hint.unwrap_or(0).min(MAX_PREALLOC_BYTES / size),
+    }
+}
+
+/// Stream a non-empty sequence into a [`Vec`], failing as soon as the
+/// sequence ends empty.
+#[cfg(feature = "alloc")]
+fn collect_seq<'de, T, A>(mut seq: A) -> Result<Vec<T>, A::Error>
+where
+    T: Deserialize<'de>,
+    A: SeqAccess<'de>,
+{
+    let Some(first) = seq.next_element()? else {
+        return Err(A::Error::invalid_length(0, &Len(None)));
+    };
+    let mut it = Vec::of_with_capacity(first, cautious::<T>(seq.size_hint()).saturating_add(1));
+    while let Some(item) = seq.next_element()? {
+        it.push(item)
+    }
+    Ok(it)
+}
+
+/// Deserialize into an existing element, reusing its resources.
+#[cfg(feature = "alloc")]
+struct InPlaceSeed<'a, T>(&'a mut T);
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for InPlaceSeed<'_, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_in_place(deserializer, self.0)
     }
 }
 
@@ -52,8 +213,167 @@ where
     where
         D: Deserializer<'de>,
     {
-        Vec::new(alloc::vec::Vec::deserialize(deserializer)?)
-            .map_err(|_| D::Error::custom(Error(())))
+        struct VecVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for VecVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a non-empty sequence")
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                collect_seq(seq)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor(PhantomData))
+    }
+
+    /// Reuses the existing allocation and elements.
+    ///
+    /// If the sequence is empty, `place` is left unchanged.
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct InPlaceVisitor<'a, T>(&'a mut Vec<T>);
+
+        impl<'de, T> Visitor<'de> for InPlaceVisitor<'_, T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = ();
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a non-empty sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let place = self.0;
+                for ix in 0..place.len() {
+                    if seq
+                        .next_element_seed(InPlaceSeed(&mut place[ix]))?
+                        .is_none()
+                    {
+                        match NonZeroUsize::new(ix) {
+                            Some(len) => {
+                                place.truncate(len);
+                                return Ok(());
+                            }
+                            None => return Err(A::Error::invalid_length(0, &Len(None))),
+                        }
+                    }
+                }
+                place.reserve(cautious::<T>(seq.size_hint()));
+                while let Some(item) = seq.next_element()? {
+                    place.push(item)
+                }
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_seq(InPlaceVisitor(place))
+    }
+}
+
+/// A [`DeserializeSeed`] which deserializes a non-empty sequence into an
+/// existing [`Vec`], reusing its allocation and elements.
+///
+/// See [`Deserialize::deserialize_in_place`].
+/// ```
+/// # use nunny::{vec, serde::InPlace};
+/// # use serde1::de::DeserializeSeed as _;
+/// let mut v = vec![String::from("hello")];
+/// InPlace(&mut v)
+///     .deserialize(&mut serde_json::Deserializer::from_str(r#"["a", "b"]"#))
+///     .unwrap();
+/// assert_eq!(v, ["a", "b"]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct InPlace<'a, T>(pub &'a mut Vec<T>);
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T> DeserializeSeed<'de> for InPlace<'_, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize_in_place(deserializer, self.0)
+    }
+}
+
+/// A [`DeserializeSeed`] which appends a sequence to an existing [`Vec`].
+///
+/// Since the [`Vec`] is already non-empty, the sequence may be empty.
+/// ```
+/// # use nunny::{vec, serde::Append};
+/// # use serde1::de::DeserializeSeed as _;
+/// let mut v = vec![1];
+/// Append(&mut v)
+///     .deserialize(&mut serde_json::Deserializer::from_str("[2, 3]"))
+///     .unwrap();
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct Append<'a, T>(pub &'a mut Vec<T>);
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T> DeserializeSeed<'de> for Append<'_, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AppendVisitor<'a, T>(&'a mut Vec<T>);
+
+        impl<'de, T> Visitor<'de> for AppendVisitor<'_, T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = ();
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                self.0.reserve(cautious::<T>(seq.size_hint()));
+                while let Some(item) = seq.next_element()? {
+                    self.0.push(item)
+                }
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_seq(AppendVisitor(self.0))
     }
 }
 #[cfg(feature = "alloc")]
@@ -69,3 +389,369 @@ where
         self.as_vec().serialize(serializer)
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T> Deserialize<'de> for HeadVec<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::deserialize(deserializer)?.into())
+    }
+}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Serialize for HeadVec<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+/// (De)serialize a [`Vec`] from either a single value, or a non-empty sequence.
+///
+/// For use with `#[serde(with = "nunny::serde::one_or_many")]` on a
+/// [`Vec`] field.
+/// ```
+/// # use nunny::serde::one_or_many;
+/// let one: nunny::Vec<String> =
+///     one_or_many::deserialize(&mut serde_json::Deserializer::from_str(r#""a""#)).unwrap();
+/// let many: nunny::Vec<String> =
+///     one_or_many::deserialize(&mut serde_json::Deserializer::from_str(r#"["a", "b"]"#)).unwrap();
+/// assert_eq!(one, ["a"]);
+/// assert_eq!(many, ["a", "b"]);
+///
+/// let mut out = vec![];
+/// one_or_many::serialize(&one, &mut serde_json::Serializer::new(&mut out)).unwrap();
+/// assert_eq!(out, br#""a""#);
+/// ```
+///
+/// A sequence is always interpreted as many values, so `T` should not itself
+/// be a sequence.
+/// This relies on [`Deserializer::deserialize_any`], so requires a
+/// self-describing format.
+///
+/// To always serialize as a sequence, use [`one_or_many::many`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod one_or_many {
+    use core::{fmt, marker::PhantomData};
+
+    use serde1::{
+        de::{
+            value::{
+                BoolDeserializer, BorrowedBytesDeserializer, BorrowedStrDeserializer,
+                BytesDeserializer, CharDeserializer, EnumAccessDeserializer, F64Deserializer,
+                I128Deserializer, I64Deserializer, MapAccessDeserializer, StrDeserializer,
+                U128Deserializer, U64Deserializer, UnitDeserializer,
+            },
+            EnumAccess, MapAccess, SeqAccess, Visitor,
+        },
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use crate::Vec;
+
+    /// Deserialize a single value, or a non-empty sequence.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_any(OneOrManyVisitor(PhantomData))
+    }
+
+    /// Serialize a single element as a scalar, or many elements as a sequence.
+    pub fn serialize<S, T>(value: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        match value.len_ne().get() {
+            1 => value.first().serialize(serializer),
+            _ => value.as_vec().serialize(serializer),
+        }
+    }
+
+    /// Deserialize a single value or a non-empty sequence, but always serialize
+    /// as a sequence.
+    pub mod many {
+        use serde1::{Serialize, Serializer};
+
+        use crate::Vec;
+
+        pub use super::deserialize;
+
+        /// Serialize as a sequence, even if there is only a single element.
+        pub fn serialize<S, T>(value: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: Serialize,
+        {
+            value.serialize(serializer)
+        }
+    }
+
+    struct OneOrManyVisitor<T>(PhantomData<T>);
+
+    macro_rules! forward_scalar {
+        ($($method:ident($ty:ty) => $de:ident);* $(;)?) => {
+            $(
+                fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+                where
+                    E: serde1::de::Error,
+                {
+                    T::deserialize($de::new(v)).map(Vec::of)
+                }
+            )*
+        };
+    }
+
+    impl<'de, T> Visitor<'de> for OneOrManyVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a single value, or a non-empty sequence")
+        }
+
+        forward_scalar! {
+            visit_bool(bool) => BoolDeserializer;
+            visit_i64(i64) => I64Deserializer;
+            visit_i128(i128) => I128Deserializer;
+            visit_u64(u64) => U64Deserializer;
+            visit_u128(u128) => U128Deserializer;
+            visit_f64(f64) => F64Deserializer;
+            visit_char(char) => CharDeserializer;
+            visit_str(&str) => StrDeserializer;
+            visit_borrowed_str(&'de str) => BorrowedStrDeserializer;
+            visit_bytes(&[u8]) => BytesDeserializer;
+            visit_borrowed_bytes(&'de [u8]) => BorrowedBytesDeserializer;
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde1::de::Error,
+        {
+            self.visit_unit()
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde1::de::Error,
+        {
+            T::deserialize(UnitDeserializer::new()).map(Vec::of)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(Vec::of)
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(Vec::of)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            T::deserialize(MapAccessDeserializer::new(map)).map(Vec::of)
+        }
+
+        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+        where
+            A: EnumAccess<'de>,
+        {
+            T::deserialize(EnumAccessDeserializer::new(data)).map(Vec::of)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            super::collect_seq(seq)
+        }
+    }
+}
+
+/// A [`Vec`] which is (de)serialized from either a single value,
+/// or a non-empty sequence.
+///
+/// See [`one_or_many`] for details.
+/// ```
+/// # use nunny::serde::OneOrMany;
+/// let one: OneOrMany<u8> = serde_json::from_str("1").unwrap();
+/// let many: OneOrMany<u8> = serde_json::from_str("[1, 2]").unwrap();
+/// assert_eq!(*one, [1]);
+/// assert_eq!(*many, [1, 2]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OneOrMany<T>(pub Vec<T>);
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> OneOrMany<T> {
+    /// Returns the inner [`Vec`].
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> core::ops::Deref for OneOrMany<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> core::ops::DerefMut for OneOrMany<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(value: OneOrMany<T>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, T> Deserialize<'de> for OneOrMany<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        one_or_many::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Serialize for OneOrMany<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        one_or_many::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    #[test]
+    fn array() {
+        let it = serde_json::from_str::<Array<u8, 2>>("[1, 2]").unwrap();
+        assert_eq!(it, [1, 2]);
+        assert_eq!(serde_json::to_string(&it).unwrap(), "[1,2]");
+        let e = serde_json::from_str::<Array<u8, 2>>("[1]").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid length 1, expected an array of length 2 at line 1 column 3"
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        let it = serde_json::from_str::<&Slice<u8>>(r#""hi""#).unwrap();
+        assert_eq!(it, b"hi");
+        let it = serde_json::from_str::<&Array<u8, 2>>(r#""hi""#).unwrap();
+        assert_eq!(it, b"hi");
+        let e = serde_json::from_str::<&Array<u8, 2>>(r#""bye""#).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid length 3, expected a sequence of length 2"
+        );
+    }
+
+    #[test]
+    fn cow() {
+        let it = serde_json::from_str::<Cow<Slice<u8>>>("[1]").unwrap();
+        assert_eq!(*it, [1]);
+        let e = serde_json::from_str::<Cow<Slice<u8>>>("[]").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid length 0, expected a non-empty sequence at line 1 column 2"
+        );
+    }
+
+    #[test]
+    fn in_place() {
+        let mut v = Vec::of(1);
+        v.extend([2, 3]);
+        let mut de = serde_json::Deserializer::from_str("[4, 5]");
+        InPlace(&mut v).deserialize(&mut de).unwrap();
+        assert_eq!(v, [4, 5]);
+
+        let mut de = serde_json::Deserializer::from_str("[]");
+        let e = InPlace(&mut v).deserialize(&mut de).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid length 0, expected a non-empty sequence at line 1 column 2"
+        );
+        assert_eq!(v, [4, 5]);
+    }
+
+    #[test]
+    fn one_or_many() {
+        let e = serde_json::from_str::<OneOrMany<u8>>("[]").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            serde_json::from_str::<Vec<u8>>("[]")
+                .unwrap_err()
+                .to_string()
+        );
+        let e = serde_json::from_str::<OneOrMany<u8>>("true").unwrap_err();
+        assert_eq!(e.classify(), serde_json::error::Category::Data);
+
+        let it = serde_json::from_str::<OneOrMany<u8>>("[1]").unwrap();
+        assert_eq!(serde_json::to_string(&it).unwrap(), "1");
+        let it = serde_json::from_str::<OneOrMany<u8>>("[1, 2]").unwrap();
+        assert_eq!(serde_json::to_string(&it).unwrap(), "[1,2]");
+    }
+}
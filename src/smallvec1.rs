@@ -0,0 +1,227 @@
+//! Non-empty small vectors, using [`smallvec`](smallvec1).
+//!
+//! [`NonEmpty<SmallVec<A>>`](NonEmpty) mirrors the API of [`Vec`](crate::Vec).
+
+use core::num::NonZeroUsize;
+
+use smallvec1::{Array, CollectionAllocErr, IntoIter, SmallVec};
+
+use crate::{NonEmpty, Slice};
+
+crate::vec_like! {
+    [A: Array] SmallVec<A> {
+        Item = A::Item;
+        IntoIter = IntoIter<A>;
+        name = "small vec";
+        path = "smallvec1::SmallVec";
+    }
+}
+
+/// [`SmallVec`] methods
+impl<A> NonEmpty<SmallVec<A>>
+where
+    A: Array,
+{
+    /// Create a [`NonEmpty`] small vec, of a single element.
+    pub fn of(item: A::Item) -> Self {
+        Self::of_with_capacity(item, 1)
+    }
+    /// Create a [`NonEmpty`] small vec, of a single element, with capacity for
+    /// `capacity` elements without (re)-allocating.
+    pub fn of_with_capacity(item: A::Item, capacity: usize) -> Self {
+        let mut inner = SmallVec::with_capacity(capacity);
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Creating a [`NonEmpty`] small vec where the first element is known.
+    pub fn of_extending<I>(first: A::Item, rest: I) -> Self
+    where
+        I: IntoIterator<Item = A::Item>,
+    {
+        let rest = rest.into_iter();
+        let mut this = Self::of_with_capacity(first, rest.size_hint().0.saturating_add(1));
+        this.extend(rest);
+        this
+    }
+    /// Create a [`NonEmpty`] small vec with `len` items, filled with
+    /// [`Clone`]s of the given `value`.
+    pub fn filled(value: A::Item, len: NonZeroUsize) -> Self
+    where
+        A::Item: Clone,
+    {
+        // Safety:
+        // - len is nonzero
+        unsafe { Self::new_unchecked(SmallVec::from_elem(value, len.get())) }
+    }
+    /// Moves the elements into a [`NonEmpty`] heap-allocated vec,
+    /// without reallocating if the elements have already spilled.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn into_vec_ne(self) -> crate::Vec<A::Item> {
+        // Safety:
+        // - originating vec is non-empty by construction
+        unsafe { crate::Vec::new_unchecked(self.into_vec().into_vec()) }
+    }
+
+    //////////////////
+    // Shimmed methods (rustdoc order)
+    //////////////////
+
+    /// Returns the known non-zero capacity.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+    /// Returns `true` if the data has spilled onto the heap.
+    ///
+    /// See [`spilled`](smallvec1::SmallVec::spilled).
+    pub fn spilled(&self) -> bool {
+        self.as_vec().spilled()
+    }
+    crate::forward_mut! {
+        "smallvec1::SmallVec";
+        pub fn push(&mut self, value: A::Item);
+        pub fn append(&mut self, other: &mut SmallVec<A>);
+        pub fn reserve(&mut self, additional: usize);
+        pub fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionAllocErr>;
+        pub fn reserve_exact(&mut self, additional: usize);
+        pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionAllocErr>;
+        pub fn shrink_to_fit(&mut self);
+        pub fn insert(&mut self, index: usize, element: A::Item);
+    }
+    /// See [`extend_from_slice`](smallvec1::SmallVec::extend_from_slice).
+    pub fn extend_from_slice(&mut self, slice: &[A::Item])
+    where
+        A::Item: Copy,
+    {
+        // Safety:
+        // - append-only operation
+        unsafe { self.as_mut_vec() }.extend_from_slice(slice)
+    }
+    /// See [`dedup_by_key`](smallvec1::SmallVec::dedup_by_key).
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut A::Item) -> K,
+        K: PartialEq,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by_key(key);
+        self.check();
+    }
+    /// See [`dedup_by`](smallvec1::SmallVec::dedup_by).
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut A::Item, &mut A::Item) -> bool,
+    {
+        // Safety:
+        // - dedup always leaves the first element
+        unsafe { self.as_mut_vec() }.dedup_by(same_bucket);
+        self.check();
+    }
+    /// See [`resize_with`](smallvec1::SmallVec::resize_with).
+    pub fn resize_with<F>(&mut self, new_len: NonZeroUsize, f: F)
+    where
+        F: FnMut() -> A::Item,
+    {
+        // Safety:
+        // - new_len is not zero, so vec cannot be emptied
+        unsafe { self.as_mut_vec() }.resize_with(new_len.get(), f);
+        self.check();
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<A, B> PartialEq<NonEmpty<SmallVec<B>>> for NonEmpty<SmallVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<SmallVec<B>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<A, B> PartialEq<SmallVec<B>> for NonEmpty<SmallVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &SmallVec<B>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<A, B> PartialEq<NonEmpty<SmallVec<A>>> for SmallVec<B>
+    where
+        A: Array,
+        B: Array,
+        B::Item: PartialEq<A::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<SmallVec<A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+mod convert {
+    use super::*;
+
+    impl<A> From<&Slice<A::Item>> for NonEmpty<SmallVec<A>>
+    where
+        A: Array,
+        A::Item: Clone,
+    {
+        fn from(value: &Slice<A::Item>) -> Self {
+            // Safety:
+            // - originating slice is non-empty by construction
+            unsafe { Self::new_unchecked(value.iter().cloned().collect()) }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A> From<crate::Vec<A::Item>> for NonEmpty<SmallVec<A>>
+    where
+        A: Array,
+    {
+        fn from(value: crate::Vec<A::Item>) -> Self {
+            // Safety:
+            // - originating vec is non-empty by construction
+            unsafe { Self::new_unchecked(SmallVec::from_vec(value.into_vec())) }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A> From<NonEmpty<SmallVec<A>>> for crate::Vec<A::Item>
+    where
+        A: Array,
+    {
+        fn from(value: NonEmpty<SmallVec<A>>) -> Self {
+            value.into_vec_ne()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spill() {
+        let mut v = NonEmpty::<SmallVec<[_; 2]>>::of(1);
+        v.extend([2]);
+        assert!(!v.spilled());
+        v.push(3);
+        assert!(v.spilled());
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(v.pop(), Some(3));
+        v.truncate(NonZeroUsize::MIN);
+        assert_eq!(v.pop(), None);
+        let collected = v.iter_ne().copied().collect::<NonEmpty<SmallVec<[_; 1]>>>();
+        assert_eq!(collected, [1]);
+    }
+}
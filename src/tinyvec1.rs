@@ -0,0 +1,329 @@
+//! Non-empty tiny vectors, using [`tinyvec`](tinyvec1).
+//!
+//! [`NonEmpty<TinyVec<A>>`](NonEmpty) mirrors the API of [`Vec`](crate::Vec),
+//! and requires the `alloc` feature.
+//! [`NonEmpty<tinyvec::ArrayVec<A>>`](NonEmpty) is always available, and like
+//! [`tinyvec::ArrayVec`](tinyvec1::ArrayVec), panics on capacity overflow
+//! unless the `try_` methods are used.
+//!
+//! Like [`tinyvec`](tinyvec1), this requires the element type to implement
+//! [`Default`].
+
+use core::num::NonZeroUsize;
+
+use tinyvec1::{Array, ArrayVec, ArrayVecIterator};
+#[cfg(feature = "alloc")]
+use tinyvec1::{TinyVec, TinyVecIterator};
+
+use crate::NonEmpty;
+#[cfg(feature = "alloc")]
+use crate::Slice;
+
+#[cfg(feature = "alloc")]
+crate::vec_like! {
+    [A: Array] TinyVec<A> {
+        Item = A::Item;
+        IntoIter = TinyVecIterator<A>;
+        name = "tiny vec";
+        path = "tinyvec1::TinyVec";
+    }
+}
+
+crate::vec_like! {
+    [A: Array] ArrayVec<A> {
+        Item = A::Item;
+        IntoIter = ArrayVecIterator<A>;
+        name = "array vec";
+        path = "tinyvec1::ArrayVec";
+    }
+}
+
+/// [`TinyVec`] methods
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A> NonEmpty<TinyVec<A>>
+where
+    A: Array,
+{
+    /// Create a [`NonEmpty`] tiny vec, of a single element.
+    pub fn of(item: A::Item) -> Self {
+        Self::of_with_capacity(item, 1)
+    }
+    /// Create a [`NonEmpty`] tiny vec, of a single element, with capacity for
+    /// `capacity` elements without (re)-allocating.
+    pub fn of_with_capacity(item: A::Item, capacity: usize) -> Self {
+        let mut inner = TinyVec::with_capacity(capacity);
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Creating a [`NonEmpty`] tiny vec where the first element is known.
+    pub fn of_extending<I>(first: A::Item, rest: I) -> Self
+    where
+        I: IntoIterator<Item = A::Item>,
+    {
+        let rest = rest.into_iter();
+        let mut this = Self::of_with_capacity(first, rest.size_hint().0.saturating_add(1));
+        this.extend(rest);
+        this
+    }
+    /// Create a [`NonEmpty`] tiny vec with `len` items, filled with
+    /// [`Clone`]s of the given `value`.
+    pub fn filled(value: A::Item, len: NonZeroUsize) -> Self
+    where
+        A::Item: Clone,
+    {
+        let mut inner = TinyVec::new();
+        inner.resize(len.get(), value);
+        // Safety:
+        // - len is nonzero
+        unsafe { Self::new_unchecked(inner) }
+    }
+    /// Moves the elements into a [`NonEmpty`] heap-allocated vec,
+    /// without reallocating if the elements are already on the heap.
+    pub fn into_vec_ne(self) -> crate::Vec<A::Item> {
+        // Safety:
+        // - originating vec is non-empty by construction
+        unsafe { crate::Vec::new_unchecked(self.into_vec().into_vec()) }
+    }
+
+    //////////////////
+    // Shimmed methods (rustdoc order)
+    //////////////////
+
+    /// Returns the known non-zero capacity.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+    /// Returns `true` if the data has moved onto the heap.
+    ///
+    /// See [`is_heap`](tinyvec1::TinyVec::is_heap).
+    pub fn is_heap(&self) -> bool {
+        self.as_vec().is_heap()
+    }
+    /// Returns `true` if the data is stored inline.
+    ///
+    /// See [`is_inline`](tinyvec1::TinyVec::is_inline).
+    pub fn is_inline(&self) -> bool {
+        self.as_vec().is_inline()
+    }
+    crate::forward_mut! {
+        "tinyvec1::TinyVec";
+        pub fn push(&mut self, value: A::Item);
+        pub fn append(&mut self, other: &mut TinyVec<A>);
+        pub fn move_to_the_heap(&mut self);
+        pub fn reserve(&mut self, additional: usize);
+        pub fn reserve_exact(&mut self, additional: usize);
+        pub fn shrink_to_fit(&mut self);
+        pub fn insert(&mut self, index: usize, element: A::Item);
+    }
+    /// See [`extend_from_slice`](tinyvec1::TinyVec::extend_from_slice).
+    pub fn extend_from_slice(&mut self, slice: &[A::Item])
+    where
+        A::Item: Clone,
+    {
+        // Safety:
+        // - append-only operation
+        unsafe { self.as_mut_vec() }.extend_from_slice(slice)
+    }
+    /// See [`resize_with`](tinyvec1::TinyVec::resize_with).
+    pub fn resize_with<F>(&mut self, new_len: NonZeroUsize, f: F)
+    where
+        F: FnMut() -> A::Item,
+    {
+        // Safety:
+        // - new_len is not zero, so vec cannot be emptied
+        unsafe { self.as_mut_vec() }.resize_with(new_len.get(), f);
+        self.check();
+    }
+}
+
+/// [`tinyvec::ArrayVec`](tinyvec1::ArrayVec) methods
+impl<A> NonEmpty<ArrayVec<A>>
+where
+    A: Array,
+{
+    /// Create a [`NonEmpty`] array vec, of a single element.
+    ///
+    /// # Panics
+    /// - If `A::CAPACITY` is zero.
+    pub fn of(item: A::Item) -> Self {
+        let mut inner = ArrayVec::new();
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        unsafe { Self::new_unchecked(inner) }
+    }
+
+    //////////////////
+    // Shimmed methods (rustdoc order)
+    //////////////////
+
+    /// Returns the known non-zero capacity.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.check();
+        unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
+    }
+    /// Returns `true` if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.as_vec().is_full()
+    }
+    crate::forward_mut! {
+        "tinyvec1::ArrayVec";
+        /// Panics if the vector is full.
+        pub fn push(&mut self, val: A::Item);
+        pub fn try_push(&mut self, val: A::Item) -> Option<A::Item>;
+        /// Panics if the vector is full.
+        pub fn insert(&mut self, index: usize, item: A::Item);
+        pub fn try_insert(&mut self, index: usize, item: A::Item) -> Option<A::Item>;
+    }
+}
+
+mod partial_eq {
+    use super::*;
+
+    impl<A, B> PartialEq<NonEmpty<ArrayVec<B>>> for NonEmpty<ArrayVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<ArrayVec<B>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    impl<A, B> PartialEq<ArrayVec<B>> for NonEmpty<ArrayVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &ArrayVec<B>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A, B> PartialEq<NonEmpty<TinyVec<B>>> for NonEmpty<TinyVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<TinyVec<B>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A, B> PartialEq<TinyVec<B>> for NonEmpty<TinyVec<A>>
+    where
+        A: Array,
+        B: Array,
+        A::Item: PartialEq<B::Item>,
+    {
+        fn eq(&self, other: &TinyVec<B>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+
+    // converse
+    //---------
+
+    impl<A, B> PartialEq<NonEmpty<ArrayVec<A>>> for ArrayVec<B>
+    where
+        A: Array,
+        B: Array,
+        B::Item: PartialEq<A::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<ArrayVec<A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A, B> PartialEq<NonEmpty<TinyVec<A>>> for TinyVec<B>
+    where
+        A: Array,
+        B: Array,
+        B::Item: PartialEq<A::Item>,
+    {
+        fn eq(&self, other: &NonEmpty<TinyVec<A>>) -> bool {
+            <[_] as PartialEq<[_]>>::eq(self, other)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod convert {
+    use super::*;
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A> From<&Slice<A::Item>> for NonEmpty<TinyVec<A>>
+    where
+        A: Array,
+        A::Item: Clone,
+    {
+        fn from(value: &Slice<A::Item>) -> Self {
+            // Safety:
+            // - originating slice is non-empty by construction
+            unsafe { Self::new_unchecked(value.iter().cloned().collect()) }
+        }
+    }
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A> From<crate::Vec<A::Item>> for NonEmpty<TinyVec<A>>
+    where
+        A: Array,
+    {
+        fn from(value: crate::Vec<A::Item>) -> Self {
+            // Safety:
+            // - originating vec is non-empty by construction
+            unsafe { Self::new_unchecked(TinyVec::Heap(value.into_vec())) }
+        }
+    }
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    impl<A> From<NonEmpty<TinyVec<A>>> for crate::Vec<A::Item>
+    where
+        A: Array,
+    {
+        fn from(value: NonEmpty<TinyVec<A>>) -> Self {
+            value.into_vec_ne()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn spill() {
+        let mut v = NonEmpty::<TinyVec<[_; 2]>>::of(1);
+        v.extend([2]);
+        assert!(v.is_inline());
+        v.push(3);
+        assert!(v.is_heap());
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(v.pop(), Some(3));
+        v.truncate(NonZeroUsize::MIN);
+        assert_eq!(v.pop(), None);
+        let collected = v.iter_ne().copied().collect::<NonEmpty<TinyVec<[_; 1]>>>();
+        assert_eq!(collected, [1]);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut v = NonEmpty::<ArrayVec<[_; 2]>>::of(1);
+        v.push(2);
+        assert!(v.is_full());
+        assert_eq!(v.try_push(3), Some(3));
+        assert_eq!(v, [1, 2]);
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), None);
+        let collected = v.iter_ne().copied().collect::<NonEmpty<ArrayVec<[_; 1]>>>();
+        assert_eq!(collected, [1]);
+    }
+}
@@ -0,0 +1,221 @@
+//! OpenAPI schemas for [`utoipa`](utoipa5).
+//!
+//! Like `utoipa`'s implementation for [`Vec`](std::vec::Vec), item schemas are
+//! inlined, and [`ToSchema::name`]s omit type parameters, which
+//! `#[derive(ToSchema)]` appends.
+//!
+//! The derive treats any field type called `Vec` as a [`std::vec::Vec`],
+//! so spell [`nunny::Vec<T>`](Vec) as `NonEmpty<Vec<T>>` in derived types.
+//! Both it and [`Box<Slice<T>>`](Slice) are named `NonEmpty_Vec_{item}`.
+//!
+//! `#[derive(ToSchema)]` requires `utoipa`'s semver-exempt `__dev::ComposeSchema`
+//! for generic field types, so the `utoipa` dependency is pinned to the
+//! versions this has been checked against.
+
+use std::borrow::Cow;
+
+use crate::{Array, Slice, Vec};
+use utoipa5::{
+    __dev::ComposeSchema,
+    openapi::{schema::ArrayBuilder, RefOr, Schema},
+    ToSchema,
+};
+
+fn array_schema<T: ToSchema>(min_items: usize, max_items: Option<usize>) -> RefOr<Schema> {
+    ArrayBuilder::new()
+        .items(T::schema())
+        .min_items(Some(min_items))
+        .max_items(max_items)
+        .into()
+}
+
+// Implementing `ComposeSchema` rather than `PartialSchema` (which it implies)
+// lets these types be fields of a `#[derive(ToSchema)]` type.
+impl<T> ComposeSchema for Vec<T>
+where
+    T: ToSchema,
+{
+    fn compose(_: std::vec::Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        array_schema::<T>(1, None)
+    }
+}
+
+impl<T> ComposeSchema for Slice<T>
+where
+    T: ToSchema,
+{
+    fn compose(generics: std::vec::Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        Vec::<T>::compose(generics)
+    }
+}
+
+impl<T> ComposeSchema for Box<Slice<T>>
+where
+    T: ToSchema,
+{
+    fn compose(generics: std::vec::Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        Vec::<T>::compose(generics)
+    }
+}
+
+impl<T, const N: usize> ComposeSchema for Array<T, N>
+where
+    T: ToSchema,
+{
+    fn compose(_: std::vec::Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        array_schema::<T>(N, Some(N))
+    }
+}
+
+// `#[derive(ToSchema)]` appends the names of type parameters, so
+// `NonEmpty<Vec<T>>` is named `NonEmpty_Vec_{item}`,
+// and `Box<Slice<T>>` matches it.
+impl<T> ToSchema for Vec<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        "NonEmpty".into()
+    }
+
+    fn schemas(schemas: &mut std::vec::Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+impl<T> ToSchema for Slice<T>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        "NonEmpty_Vec".into()
+    }
+
+    fn schemas(schemas: &mut std::vec::Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+impl<T> ToSchema for Box<Slice<T>>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        Slice::<T>::name()
+    }
+
+    fn schemas(schemas: &mut std::vec::Vec<(String, RefOr<Schema>)>) {
+        Slice::<T>::schemas(schemas)
+    }
+}
+
+impl<T, const N: usize> ToSchema for Array<T, N>
+where
+    T: ToSchema,
+{
+    fn name() -> Cow<'static, str> {
+        format!("Array_of_{}_of", N).into()
+    }
+
+    fn schemas(schemas: &mut std::vec::Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa5::PartialSchema as _;
+
+    use super::*;
+
+    #[test]
+    fn test_schema() {
+        assert_eq!(
+            serde_json::to_value(Vec::<bool>::schema()).unwrap(),
+            serde_json::json!({
+              "type": "array",
+              "items": { "type": "boolean" },
+              "minItems": 1
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Array::<bool, 2>::schema()).unwrap(),
+            serde_json::json!({
+              "type": "array",
+              "items": { "type": "boolean" },
+              "minItems": 2,
+              "maxItems": 2
+            })
+        );
+        assert_eq!(Box::<Slice<bool>>::name(), "NonEmpty_Vec");
+    }
+
+    // `#[derive(ToSchema)]` assumes that `Vec` is `std::vec::Vec`, so don't import ours
+    mod derive {
+        use utoipa5 as utoipa;
+        use utoipa5::{OpenApi, ToSchema};
+
+        use crate::{Array, NonEmpty, Slice};
+
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        enum Kind {
+            Cat,
+            Dog,
+        }
+
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        struct Pet {
+            kind: Kind,
+        }
+
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        struct Owner {
+            pets: NonEmpty<std::vec::Vec<Pet>>,
+            pair: Array<Pet, 2>,
+            boxed: Box<Slice<Pet>>,
+        }
+
+        #[test]
+        fn derive() {
+            #[derive(OpenApi)]
+            #[openapi(components(schemas(Owner)))]
+            struct Doc;
+
+            let doc = serde_json::to_value(Doc::openapi()).unwrap();
+            let schemas = &doc["components"]["schemas"];
+            let pet = serde_json::json!({
+              "type": "object",
+              "required": ["kind"],
+              "properties": { "kind": { "$ref": "#/components/schemas/Kind" } }
+            });
+            assert_eq!(
+                schemas["Owner"]["properties"],
+                serde_json::json!({
+                  "pets": { "$ref": "#/components/schemas/NonEmpty_Vec_Pet" },
+                  "pair": { "$ref": "#/components/schemas/Array_of_2_of_Pet" },
+                  "boxed": { "$ref": "#/components/schemas/NonEmpty_Vec_Pet" },
+                })
+            );
+            assert_eq!(
+                schemas["NonEmpty_Vec_Pet"],
+                serde_json::json!({ "type": "array", "items": pet, "minItems": 1 })
+            );
+            assert_eq!(
+                schemas["Array_of_2_of_Pet"],
+                serde_json::json!({ "type": "array", "items": pet, "minItems": 2, "maxItems": 2 })
+            );
+            assert_eq!(
+                schemas
+                    .as_object()
+                    .unwrap()
+                    .keys()
+                    .collect::<std::vec::Vec<_>>(),
+                ["Array_of_2_of_Pet", "Kind", "NonEmpty_Vec_Pet", "Owner"]
+            );
+            assert!(schemas["Kind"].is_object());
+        }
+    }
+}
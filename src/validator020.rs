@@ -0,0 +1,113 @@
+//! Field validation with [`validator`](validator020).
+//!
+//! The minimum length is already enforced by the types,
+//! so `#[validate(length(max = ..))]` is usually all that's needed.
+
+use validator020::{Validate, ValidateLength, ValidationErrors};
+
+use crate::{Array, Slice, Vec};
+
+impl<T> ValidateLength<u64> for Slice<T> {
+    fn length(&self) -> Option<u64> {
+        self.as_slice().length()
+    }
+}
+
+impl<T, const N: usize> ValidateLength<u64> for Array<T, N> {
+    fn length(&self) -> Option<u64> {
+        self.as_slice().length()
+    }
+}
+
+impl<T> ValidateLength<u64> for Vec<T> {
+    fn length(&self) -> Option<u64> {
+        self.as_slice().length()
+    }
+}
+
+impl<T> Validate for Slice<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+impl<T, const N: usize> Validate for Array<T, N>
+where
+    T: Validate,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length() {
+        let v = Vec::of_extending(1, [2, 3]);
+        assert_eq!(v.length(), Some(3));
+        assert!(v.validate_length(None, Some(3), None));
+        assert!(!v.as_slice_ne().validate_length(None, Some(2), None));
+        assert!(Array::new([0; 2])
+            .unwrap()
+            .validate_length(None, None, Some(2)));
+    }
+
+    // `length` errors include the value, so fields must be `Serialize`
+    #[derive(Validate, serde1::Serialize)]
+    #[serde(crate = "serde1")]
+    struct Host {
+        #[validate(length(min = 1))]
+        name: std::string::String,
+    }
+
+    #[derive(Validate)]
+    struct Config {
+        #[validate(length(max = 2), nested)]
+        hosts: Vec<Host>,
+    }
+
+    fn host(name: &str) -> Host {
+        Host { name: name.into() }
+    }
+
+    #[test]
+    fn derive() {
+        use validator020::ValidationErrorsKind;
+
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("b")]),
+        };
+        assert!(config.validate().is_ok());
+
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("")]),
+        };
+        let errors = config.validate().unwrap_err();
+        let ValidationErrorsKind::List(items) = &errors.errors()["hosts"] else {
+            panic!("expected errors for the items of hosts")
+        };
+        assert_eq!(items.keys().collect::<std::vec::Vec<_>>(), [&1]);
+        assert!(items[&1].field_errors().contains_key("name"));
+
+        let config = Config {
+            hosts: Vec::of_extending(host("a"), [host("b"), host("c")]),
+        };
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.field_errors()["hosts"][0].code, "length");
+    }
+}
@@ -18,21 +18,6 @@ where
     }
 }
 
-macro_rules! forward_mut {
-    ($( $(#[$meta:meta])* $vis:vis fn $ident:ident(&mut self $(,$arg:ident: $ty:ty)* $(,)?) $(-> $ret:ty)?);* $(;)?) => {
-        $(
-            $(#[$meta])*
-            ///
-            #[doc = concat!("See [`", stringify!($ident), "`](alloc::vec::Vec::", stringify!($ident), ").")]
-            $vis fn $ident(&mut self $(, $arg: $ty)*) $(-> $ret)? {
-                // Safety:
-                // - operation does not remove elements
-                unsafe { self.as_mut_vec() }.$ident($($arg),*)
-            }
-        )*
-    };
-}
-
 /// [`Vec`] methods
 impl<T> Vec<T> {
     ///////////
@@ -125,6 +110,51 @@ impl<T> Vec<T> {
         // - len is nonzero
         unsafe { Self::new_unchecked(inner) }
     }
+
+    /// Fallible version of [`Self::of`], returning an error if the allocator
+    /// reports a failure.
+    /// ```
+    /// let v = nunny::Vec::try_of("hello").unwrap();
+    /// assert_eq!(v, ["hello"]);
+    /// ```
+    pub fn try_of(item: T) -> Result<Self, TryReserveError> {
+        Self::try_of_with_capacity(item, 1)
+    }
+    /// Fallible version of [`Self::of_with_capacity`], returning an error if
+    /// the allocator reports a failure.
+    /// ```
+    /// let v = nunny::Vec::try_of_with_capacity(1u8, 8).unwrap();
+    /// assert!(v.capacity().get() >= 8);
+    /// assert!(nunny::Vec::try_of_with_capacity(1u8, usize::MAX).is_err());
+    /// ```
+    pub fn try_of_with_capacity(item: T, capacity: usize) -> Result<Self, TryReserveError> {
+        let mut inner = alloc::vec::Vec::new();
+        inner.try_reserve_exact(capacity.max(1))?;
+        inner.push(item);
+        // Safety:
+        // - pushing the element succeeded
+        Ok(unsafe { Self::new_unchecked(inner) })
+    }
+    /// Fallible version of [`Self::filled`], returning an error if the
+    /// allocator reports a failure.
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// # use nunny::nonzero;
+    /// let v = nunny::Vec::try_filled('a', nonzero!(3)).unwrap();
+    /// assert_eq!(v, ['a', 'a', 'a']);
+    /// assert!(nunny::Vec::try_filled(0u8, NonZeroUsize::MAX).is_err());
+    /// ```
+    pub fn try_filled(value: T, len: NonZeroUsize) -> Result<Self, TryReserveError>
+    where
+        T: Clone,
+    {
+        let mut inner = alloc::vec::Vec::new();
+        inner.try_reserve_exact(len.get())?;
+        inner.resize(len.get(), value);
+        // Safety:
+        // - len is nonzero
+        Ok(unsafe { Self::new_unchecked(inner) })
+    }
     fn check(&self) {
         debug_assert_ne!(self.inner.len(), 0)
     }
@@ -166,7 +196,8 @@ impl<T> Vec<T> {
         unsafe { crate::non_zero_usize(self.as_vec().capacity()) }
     }
 
-    forward_mut! {
+    crate::forward_mut! {
+        "alloc::vec::Vec";
         pub fn reserve(&mut self, additional: usize);
         pub fn reserve_exact(&mut self, additional: usize);
         pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
@@ -217,7 +248,8 @@ impl<T> Vec<T> {
         self.check();
     }
 
-    forward_mut! {
+    crate::forward_mut! {
+        "alloc::vec::Vec";
         pub fn insert(&mut self, index: usize, element: T);
     }
 
@@ -242,10 +274,44 @@ impl<T> Vec<T> {
         unsafe { self.as_mut_vec() }.dedup_by(same_bucket);
         self.check();
     }
-    forward_mut! {
+    crate::forward_mut! {
+        "alloc::vec::Vec";
         pub fn push(&mut self, value: T);
         pub fn append(&mut self, other: &mut alloc::vec::Vec<T>);
     }
+    /// Fallible version of [`Self::push`], returning an error if the
+    /// allocator reports a failure.
+    ///
+    /// The vec is unchanged on error.
+    /// ```
+    /// let mut v = nunny::vec![1];
+    /// v.try_push(2).unwrap();
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        self.try_reserve(1)?;
+        self.push(value);
+        Ok(())
+    }
+    /// Fallible version of [`extend_from_slice`](alloc::vec::Vec::extend_from_slice),
+    /// returning an error if the allocator reports a failure.
+    ///
+    /// The vec is unchanged on error.
+    /// ```
+    /// let mut v = nunny::vec![1];
+    /// v.try_extend_from_slice(&[2, 3]).unwrap();
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        self.try_reserve(other.len())?;
+        // Safety:
+        // - append-only operation
+        unsafe { self.as_mut_vec() }.extend_from_slice(other);
+        Ok(())
+    }
 
     // pub fn split_off(&mut self, at: NonZeroUsize)
 
@@ -268,7 +334,8 @@ impl<T> Vec<T> {
         // - originating slice is non-empty by construction
         unsafe { Slice::new_mut_unchecked(inner) }
     }
-    forward_mut! {
+    crate::forward_mut! {
+        "alloc::vec::Vec";
         pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>];
     }
 }
@@ -498,3 +565,27 @@ mod convert_std {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Zero-sized elements never allocate, so only the length can overflow.
+
+    #[test]
+    fn try_push_err_leaves_vec_unchanged() {
+        let mut v = Vec::of(());
+        // Safety:
+        // - zero-sized elements need no initialization
+        unsafe { v.set_len(NonZeroUsize::MAX) };
+        assert!(v.try_push(()).is_err());
+        assert_eq!(v.len(), usize::MAX);
+    }
+
+    #[test]
+    fn try_extend_from_slice_err_leaves_vec_unchanged() {
+        let mut v = Vec::of_extending((), [()]);
+        assert!(v.try_extend_from_slice(&[(); usize::MAX]).is_err());
+        assert_eq!(v.len(), 2);
+    }
+}
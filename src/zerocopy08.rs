@@ -0,0 +1,59 @@
+//! Byte-level conversions with [`zerocopy`](zerocopy08).
+//!
+//! [`NonEmpty`](crate::NonEmpty) derives [`KnownLayout`](zerocopy08::KnownLayout), [`Immutable`] and [`IntoBytes`],
+//! so [`Slice`]s and [`Array`](crate::Array)s may be viewed as bytes.
+//!
+//! [`FromBytes`] is deliberately not implemented, since an empty byte slice
+//! would produce an empty [`Slice`].
+//! Use [`Slice::ref_from_bytes`] and [`Slice::mut_from_bytes`] instead.
+
+use zerocopy08::{FromBytes, Immutable, IntoBytes};
+
+use crate::Slice;
+
+impl<T> Slice<T> {
+    /// Interpret `bytes` as a [`Slice`] of `T`.
+    ///
+    /// Returns [`None`] if `bytes` is empty, is not aligned for `T`,
+    /// or its length is not a multiple of the size of `T`.
+    ///
+    /// See [`FromBytes::ref_from_bytes`].
+    pub fn ref_from_bytes(bytes: &[u8]) -> Option<&Self>
+    where
+        T: FromBytes + Immutable,
+    {
+        Slice::new(<[T]>::ref_from_bytes(bytes).ok()?)
+    }
+    /// Interpret `bytes` as a [`Slice`] of `T`.
+    ///
+    /// See [`Self::ref_from_bytes`].
+    pub fn mut_from_bytes(bytes: &mut [u8]) -> Option<&mut Self>
+    where
+        T: FromBytes + IntoBytes,
+    {
+        Slice::new_mut(<[T]>::mut_from_bytes(bytes).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Array;
+
+    #[test]
+    fn bytes() {
+        let words = [0x01020304u32.to_be(), 0x05060708u32.to_be()];
+        let words = Slice::new(&words).unwrap();
+        assert_eq!(words.as_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(Array::of(1u8).as_bytes(), [1]);
+
+        let words = Slice::<[u8; 4]>::ref_from_bytes(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        assert_eq!(words.as_slice(), [[1, 2, 3, 4], [5, 6, 7, 8]]);
+        assert_eq!(Slice::<[u8; 4]>::ref_from_bytes(&[]), None);
+        assert_eq!(Slice::<[u8; 4]>::ref_from_bytes(&[1, 2, 3]), None);
+
+        let mut bytes = [0u8; 2];
+        Slice::<u8>::mut_from_bytes(&mut bytes).unwrap()[0] = 1;
+        assert_eq!(bytes, [1, 0]);
+    }
+}